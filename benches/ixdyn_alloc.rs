@@ -0,0 +1,52 @@
+//! Counts the heap allocations made while building `IxDyn` shapes of increasing rank.
+//!
+//! Run with `cargo bench --bench ixdyn_alloc`. Shapes of up to four dimensions are stored inline
+//! and should report zero allocations per shape.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+use numru::{Dimension, IxDyn};
+
+const ITERATIONS: usize = 1_000_000;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn main() {
+    for rank in [1, 2, 3, 4, 5, 8] {
+        let dims = vec![3; rank];
+        let before = ALLOCATIONS.load(Ordering::Relaxed);
+        let start = Instant::now();
+        for _ in 0..ITERATIONS {
+            let ix = IxDyn::new(black_box(&dims));
+            black_box(ix.size());
+        }
+        let elapsed = start.elapsed();
+        let allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+
+        println!(
+            "rank {}: {:.2} allocations per shape, {:?} per shape",
+            rank,
+            allocations as f64 / ITERATIONS as f64,
+            elapsed / ITERATIONS as u32
+        );
+    }
+}
@@ -0,0 +1,15 @@
+use numru::arr;
+
+fn main() {
+    let a = arr![1, 2, 3];
+    let a_squared = a.map(|x| x * x);
+    println!("a.map(|x| x * x) = {:?}", a_squared);
+
+    let celsius = arr![[-40.0, 0.0], [37.0, 100.0]];
+    let fahrenheit = celsius.map(|c| c * 9.0 / 5.0 + 32.0);
+    println!("celsius.map(|c| c * 9.0 / 5.0 + 32.0) = {:?}", fahrenheit);
+
+    let b = arr![[3, -1, 0], [-7, 5, 2]];
+    let b_positive = b.map(|x| x > 0);
+    println!("b.map(|x| x > 0) = {:?}", b_positive);
+}
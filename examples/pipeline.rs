@@ -0,0 +1,25 @@
+use numru::arr;
+
+fn main() {
+    let a = arr![1, 2, 3, 4, 5, 6];
+    println!("a.shape() = {:?}", a.shape());
+
+    let b = a.astype::<f64>();
+    println!("b.dtype() = {:?}", b.dtype());
+
+    let c = b.reshape([2, 3]).unwrap();
+    println!("c.shape() = {:?}", c.shape());
+    c.visualize().execute();
+
+    let c_mean_axis_0 = c.mean().axis(0).compute();
+    println!("c.mean().axis(0) = {:?}", c_mean_axis_0);
+
+    let pipeline = arr![1, 2, 3, 4, 5, 6]
+        .astype::<f64>()
+        .reshape([3, 2])
+        .unwrap()
+        .mean()
+        .axis(1)
+        .compute();
+    println!("pipeline = {:?}", pipeline);
+}
@@ -0,0 +1,32 @@
+use numru::arr;
+use std::f64::consts::{E, PI, TAU};
+
+fn main() {
+    let a = arr![42, -17, 256, 3, 99, -8];
+    let a_sum = a.sum().compute();
+    println!("a.sum() = {:?}", a_sum);
+    // Note: For 1D arrays, axis doesn't make sense, but this is for demonstration
+    let a_sum = a.sum().axis(0).compute();
+    println!("a.sum().axis(0) = {:?}", a_sum);
+
+    let b = arr![[TAU, -PI, 1.61], [E, 0.98, -7.42], [4.67, -0.45, 8.88]];
+    let b_sum = b.sum().compute();
+    println!("b.sum() = {:?}", b_sum);
+    let b_sum = b.sum().axis(0).compute();
+    println!("b.sum().axis(0) = {:?}", b_sum);
+    let b_sum = b.sum().axis(1).compute();
+    println!("b.sum().axis(1) = {:?}", b_sum);
+
+    let c = arr![
+        [[101, 202, 303], [404, 505, 606]],
+        [[-707, -808, -909], [111, 222, 333]]
+    ];
+    let c_sum = c.sum().compute();
+    println!("c.sum() = {:?}", c_sum);
+    let c_sum = c.sum().axis(0).compute();
+    println!("c.sum().axis(0) = {:?}", c_sum);
+    let c_sum = c.sum().axis(1).compute();
+    println!("c.sum().axis(1) = {:?}", c_sum);
+    let c_sum = c.sum().axis(2).compute();
+    println!("c.sum().axis(2) = {:?}", c_sum);
+}
@@ -1,20 +1,174 @@
-use num_traits::{One, Zero};
+use num_traits::{AsPrimitive, CheckedMul, One, Zero};
+use std::ops::{Add, Div, Index, IndexMut, Mul, Sub};
 
+use crate::operations::NanPolicy;
+use crate::shape::broadcast_shapes;
+use crate::view::AsView;
 use crate::ArrayError;
-use crate::{Dimension, Shape};
-use std::fmt::Debug;
+use crate::{ArrayView, Dimension, Ix, IxDyn, Shape};
+use std::fmt::{Debug, Formatter};
+
+/// Number of leading and trailing elements shown by the `Debug` output of large arrays.
+const DEBUG_EDGE_ITEMS: usize = 3;
 
 /// Represents a multi-dimensional array with elements of type `T` and dimension `D`.
-#[derive(Debug)]
 pub struct Array<T, D: Dimension> {
     data: Vec<T>,
     shape: Shape<D>,
 }
 
+impl<T: Debug, D: Dimension> Debug for Array<T, D> {
+    /// Formats the `Array` with its shape and element type first, followed by a preview of the
+    /// data. Arrays with more than `2 * DEBUG_EDGE_ITEMS` elements only show the first and last
+    /// few elements.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Array")
+            .field("shape", &self.shape.dims())
+            .field("dtype", &format_args!("{}", std::any::type_name::<T>()))
+            .field("data", &DataPreview(&self.data))
+            .finish()
+    }
+}
+
+impl<T: PartialEq, D: Dimension> PartialEq for Array<T, D> {
+    /// Two arrays are equal when they have the same shape and the same elements.
+    fn eq(&self, other: &Self) -> bool {
+        self.shape.dims() == other.shape.dims() && self.data == other.data
+    }
+}
+
+impl<T, const N: usize> Index<[usize; N]> for Array<T, Ix<N>> {
+    type Output = T;
+
+    /// Returns the element at `index`, panicking with the `ArrayError::IndexOutOfBounds` message
+    /// if any coordinate is out of range.
+    fn index(&self, index: [usize; N]) -> &T {
+        self.at(index)
+    }
+}
+
+impl<T, const N: usize> IndexMut<[usize; N]> for Array<T, Ix<N>> {
+    /// Returns the element at `index` mutably, panicking with the `ArrayError::IndexOutOfBounds`
+    /// message if any coordinate is out of range.
+    fn index_mut(&mut self, index: [usize; N]) -> &mut T {
+        match self.flat_index(&index) {
+            Ok(offset) => &mut self.data[offset],
+            Err(err) => panic!("{}", err),
+        }
+    }
+}
+
+impl<T> From<Vec<T>> for Array<T, Ix<1>> {
+    /// Converts a vector into a 1D array of the same length.
+    fn from(data: Vec<T>) -> Self {
+        let len = data.len();
+        Array {
+            data,
+            shape: Shape::new(Ix::<1>::new([len])),
+        }
+    }
+}
+
+impl<T> Array<T, Ix<1>> {
+    /// Collects the items of an iterator into a 1D array.
+    pub fn from_iter_1d<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Array::from(iter.into_iter().collect::<Vec<T>>())
+    }
+}
+
+impl<T, D: Dimension> From<Array<T, D>> for Vec<T> {
+    /// Consumes the array and returns its data as a flat vector in row-major order.
+    fn from(array: Array<T, D>) -> Self {
+        array.data
+    }
+}
+
+/// Helper for printing a possibly truncated view of an array's data.
+struct DataPreview<'a, T>(&'a [T]);
+
+impl<T: Debug> Debug for DataPreview<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let data = self.0;
+        if data.len() <= 2 * DEBUG_EDGE_ITEMS {
+            return f.debug_list().entries(data).finish();
+        }
+
+        f.debug_list()
+            .entries(&data[..DEBUG_EDGE_ITEMS])
+            .entry(&format_args!("..."))
+            .entries(&data[data.len() - DEBUG_EDGE_ITEMS..])
+            .finish()
+    }
+}
+
+/// Returns the number of elements described by `dims`, or `ArrayError::Overflow` if it does
+/// not fit in `usize`.
+fn checked_size<D: Dimension>(dims: &D) -> Result<usize, ArrayError> {
+    dims.checked_size().ok_or_else(|| {
+        ArrayError::Overflow(format!(
+            "Shape {:?} describes more than {} elements",
+            dims.dims(),
+            usize::MAX
+        ))
+    })
+}
+
+/// Returns `ArrayError::InvalidArgument` if the lower clipping bound exceeds the upper one.
+fn check_clip_bounds<T: PartialOrd + Debug>(min: &T, max: &T) -> Result<(), ArrayError> {
+    if min > max {
+        return Err(ArrayError::InvalidArgument(format!(
+            "Lower bound {:?} is greater than upper bound {:?}",
+            min, max
+        )));
+    }
+    Ok(())
+}
+
+/// Bounds `x` to `[min, max]`, leaving values that do not compare with the bounds untouched.
+fn clip_value<T: PartialOrd>(x: T, min: T, max: T) -> T {
+    if x < min {
+        min
+    } else if x > max {
+        max
+    } else {
+        x
+    }
+}
+
+/// Lazily yields, for every element of an array of shape `out` in row-major order, the offset of
+/// the element of an array of shape `dims` that broadcasts to it.
+fn broadcast_offsets(dims: &[usize], out: &[usize]) -> impl Iterator<Item = usize> {
+    let size: usize = out.iter().product();
+    let lead = out.len() - dims.len();
+
+    let mut strides = vec![0; out.len()];
+    let mut stride = 1;
+    for (axis, &dim) in dims.iter().enumerate().rev() {
+        if dim != 1 {
+            strides[lead + axis] = stride;
+        }
+        stride *= dim;
+    }
+
+    let out = out.to_vec();
+    (0..size).map(move |flat| {
+        let mut remainder = flat;
+        let mut offset = 0;
+        for (&len, &stride) in out.iter().zip(&strides).rev() {
+            offset += (remainder % len) * stride;
+            remainder /= len;
+        }
+        offset
+    })
+}
+
 impl<T, D: Dimension> Array<T, D> {
     /// Constructs a new `Array` from a vector of data and a shape.
+    ///
+    /// Returns `ArrayError::Overflow` if the number of elements described by the shape does not
+    /// fit in `usize`, and `ArrayError::DimensionMismatch` if it differs from the data length.
     pub fn new(data: Vec<T>, shape: Shape<D>) -> Result<Self, ArrayError> {
-        let expected_size = shape.size();
+        let expected_size = checked_size(shape.raw_dim())?;
         if data.len() != expected_size {
             return Err(ArrayError::DimensionMismatch {
                 expected: expected_size,
@@ -33,456 +187,4889 @@ impl<T, D: Dimension> Array<T, D> {
     pub fn shape(&self) -> &Shape<D> {
         &self.shape
     }
-}
 
-impl<T: Zero + One + Copy, D: Dimension> Array<T, D> {
-    /// Replaces all elements in the array with zeros using num_traits::Zero.
-    /// The shape and dimension of the array are preserved.
-    pub fn zeros(&mut self) {
-        let zero = T::zero(); // Use Zero::zero() instead of T::default()
-        self.data.iter_mut().for_each(|x| *x = zero);
+    /// Returns an iterator over the elements in row-major order.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
     }
 
-    /// Replaces all elements in the array with ones using num_traits::One.
-    /// The shape and dimension of the array are preserved.
-    pub fn ones(&mut self) {
-        let one = T::one(); // Use One::one() to get the one value
-        self.data.iter_mut().for_each(|x| *x = one);
+    /// Returns an iterator over mutable references to the elements in row-major order.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.data.iter_mut()
     }
-}
 
-impl<D: Dimension> Array<i64, D> {
-    /// Returns the data type string for an array of `i64`.
-    pub fn dtype(&self) -> &'static str {
-        "int64"
+    /// Returns the number of dimensions of the array.
+    pub fn ndim(&self) -> usize {
+        self.shape.raw_dim().ndim()
     }
-}
 
-impl<D: Dimension> Array<f64, D> {
-    /// Returns the data type string for an array of `f64`.
-    pub fn dtype(&self) -> &'static str {
-        "float64"
+    /// Returns the dimensions of the array as an owned vector.
+    pub fn shape_vec(&self) -> Vec<usize> {
+        self.shape.dims().to_vec()
     }
-}
 
-impl<T, D: Dimension> Array<T, D>
-where
-    T: PartialOrd + Copy,
-{
-    /// Computes the maximum value(s) of the array along a specified axis or for the whole array.
-    pub fn max_compute(&self, axis: Option<usize>) -> Result<Vec<T>, ArrayError> {
-        if self.data.is_empty() {
-            return Err(ArrayError::EmptyArray);
+    /// Returns the row-major strides of the array, in elements.
+    fn standard_strides(&self) -> Vec<isize> {
+        let dims = self.shape.dims();
+        let mut strides = vec![1isize; dims.len()];
+        for axis in (0..dims.len().saturating_sub(1)).rev() {
+            strides[axis] = strides[axis + 1] * dims[axis + 1] as isize;
         }
+        strides
+    }
 
-        let raw_dim = self.shape.raw_dim();
-        let ndim = raw_dim.ndim();
+    /// Returns a borrowed view of the whole array in its natural row-major layout.
+    pub fn view(&self) -> ArrayView<'_, T, D> {
+        ArrayView::new(&self.data, 0, self.shape.clone(), self.standard_strides())
+    }
 
-        if let Some(axis) = axis {
-            if axis >= ndim {
-                return Err(ArrayError::InvalidAxis(format!(
-                    "Axis {} is out of bounds for array with {} dimensions",
-                    axis, ndim
-                )));
-            }
+    /// Returns a view of the array reversed along every axis, without copying any elements.
+    ///
+    /// The view walks the data with negative strides, so iterating it yields the elements in the
+    /// same order as the owned array returned by `flip`.
+    pub fn flip_view(&self) -> ArrayView<'_, T, D> {
+        let strides = self.standard_strides().iter().map(|&s| -s).collect();
+        let offset = self.data.len().saturating_sub(1);
+        ArrayView::new(&self.data, offset, self.shape.clone(), strides)
+    }
+
+    /// Returns a new array reversed along every axis.
+    pub fn flip(&self) -> Array<T, D>
+    where
+        T: Clone,
+    {
+        Array {
+            data: self.data.iter().rev().cloned().collect(),
+            shape: self.shape.clone(),
         }
+    }
 
-        match ndim {
-            1 => Ok(vec![*self
-                .data
-                .iter()
-                .max_by(|a, b| a.partial_cmp(b).unwrap())
-                .ok_or(ArrayError::EmptyArray)?]),
-            2 => {
-                let rows = raw_dim.dims()[0];
-                let cols = raw_dim.dims()[1];
-
-                if let Some(axis) = axis {
-                    if axis == 0 {
-                        (0..cols)
-                            .map(|col| {
-                                (0..rows)
-                                    .map(|row| self.data[row * cols + col])
-                                    .max_by(|a, b| a.partial_cmp(b).unwrap())
-                                    .ok_or(ArrayError::EmptyArray)
-                            })
-                            .collect::<Result<Vec<T>, _>>()
-                    } else {
-                        (0..rows)
-                            .map(|row| {
-                                self.data[row * cols..(row + 1) * cols]
-                                    .iter()
-                                    .max_by(|a, b| a.partial_cmp(b).unwrap())
-                                    .map(|&v| v)
-                                    .ok_or(ArrayError::EmptyArray)
-                            })
-                            .collect::<Result<Vec<T>, _>>()
-                    }
-                } else {
-                    Ok(vec![*self
-                        .data
-                        .iter()
-                        .max_by(|a, b| a.partial_cmp(b).unwrap())
-                        .ok_or(ArrayError::EmptyArray)?])
-                }
-            }
-            3 => {
-                let depth = raw_dim.dims()[0];
-                let rows = raw_dim.dims()[1];
-                let cols = raw_dim.dims()[2];
-
-                if let Some(axis) = axis {
-                    match axis {
-                        0 => (0..rows * cols)
-                            .map(|i| {
-                                (0..depth)
-                                    .map(|d| self.data[d * rows * cols + i])
-                                    .max_by(|a, b| a.partial_cmp(b).unwrap())
-                                    .ok_or(ArrayError::EmptyArray)
-                            })
-                            .collect::<Result<Vec<T>, _>>(),
-                        1 => (0..depth)
-                            .flat_map(|d| {
-                                (0..cols).map(move |c| {
-                                    (0..rows)
-                                        .map(|r| self.data[d * rows * cols + r * cols + c])
-                                        .max_by(|a, b| a.partial_cmp(b).unwrap())
-                                        .ok_or(ArrayError::EmptyArray)
-                                })
-                            })
-                            .collect::<Result<Vec<T>, _>>(),
-                        2 => (0..depth)
-                            .flat_map(|d| {
-                                (0..rows).map(move |r| {
-                                    let row_start = d * rows * cols + r * cols;
-                                    self.data[row_start..row_start + cols]
-                                        .iter()
-                                        .max_by(|a, b| a.partial_cmp(b).unwrap())
-                                        .map(|&v| v)
-                                        .ok_or(ArrayError::EmptyArray)
-                                })
-                            })
-                            .collect::<Result<Vec<T>, _>>(),
-                        _ => unreachable!(),
-                    }
-                } else {
-                    Ok(vec![*self
-                        .data
-                        .iter()
-                        .max_by(|a, b| a.partial_cmp(b).unwrap())
-                        .ok_or(ArrayError::EmptyArray)?])
+    /// Applies `f` to a copy of every element and returns a new array of the results.
+    /// The shape of the array is preserved, while the element type may change.
+    pub fn map<U, F>(&self, f: F) -> Array<U, D>
+    where
+        T: Clone,
+        F: Fn(T) -> U,
+    {
+        Array {
+            data: self.data.iter().cloned().map(f).collect(),
+            shape: self.shape.clone(),
+        }
+    }
+
+    /// Splits the underlying data into two disjoint mutable slices at `index` along `axis`.
+    ///
+    /// Only axis 0 is supported, since that is the only axis where the split is contiguous in
+    /// row-major layout. The first slice holds the elements before `index`, the second the rest,
+    /// which allows both halves to be processed independently (e.g. on separate threads).
+    pub fn split_at_mut_axis(
+        &mut self,
+        index: usize,
+        axis: usize,
+    ) -> Result<(&mut [T], &mut [T]), ArrayError> {
+        let dims = self.shape.dims();
+        let ndim = dims.len();
+
+        if axis >= ndim {
+            return Err(ArrayError::InvalidAxis(format!(
+                "Axis {} is out of bounds for array with {} dimensions",
+                axis, ndim
+            )));
+        }
+        if axis != 0 {
+            return Err(ArrayError::InvalidAxis(format!(
+                "Axis {} cannot be split contiguously, only axis 0 is supported",
+                axis
+            )));
+        }
+        if index > dims[0] {
+            return Err(ArrayError::IndexOutOfBounds(format!(
+                "Split index {} is out of bounds for axis 0 with length {}",
+                index, dims[0]
+            )));
+        }
+
+        let lane_size: usize = dims[1..].iter().product();
+        Ok(self.data.split_at_mut(index * lane_size))
+    }
+
+    /// Returns a new array with the same data interpreted under a different shape.
+    ///
+    /// The data is cloned into the new array and its row-major order is preserved. Returns
+    /// `ArrayError::DimensionMismatch` if the number of elements described by `dims` differs
+    /// from the size of this array.
+    pub fn reshape<const M: usize>(&self, dims: [usize; M]) -> Result<Array<T, Ix<M>>, ArrayError>
+    where
+        T: Clone,
+    {
+        Array::new(self.data.clone(), Shape::new(Ix::<M>::new(dims)))
+    }
+
+    /// Returns a new 1D array holding all elements in row-major order, the same order as
+    /// `data()`.
+    pub fn flatten(&self) -> Array<T, Ix<1>>
+    where
+        T: Clone,
+    {
+        Array::from(self.data.clone())
+    }
+
+    /// Alias for `flatten`, matching NumPy's `ravel`.
+    pub fn ravel(&self) -> Array<T, Ix<1>>
+    where
+        T: Clone,
+    {
+        self.flatten()
+    }
+
+    /// Returns a new array with every axis of length one removed, keeping the data intact.
+    ///
+    /// Since the resulting rank depends on the shape, the result has a dynamic dimension. When
+    /// every axis has length one, a single axis of length one is kept rather than producing a
+    /// zero-dimensional array.
+    pub fn squeeze(&self) -> Array<T, IxDyn>
+    where
+        T: Clone,
+    {
+        let mut dims: Vec<usize> = self
+            .shape
+            .dims()
+            .iter()
+            .copied()
+            .filter(|&d| d != 1)
+            .collect();
+        if dims.is_empty() {
+            dims.push(1);
+        }
+
+        Array {
+            data: self.data.clone(),
+            shape: Shape::new(IxDyn::new(&dims)),
+        }
+    }
+
+    /// Swaps the rows and columns of a 2D array of any dimension type, like `transpose` on
+    /// `Array<T, Ix<2>>`.
+    ///
+    /// Returns `ArrayError::UnimplementedDimension` for any other rank, where `permute_axes`
+    /// reorders the axes instead, and for a dimension type whose `with_dims` cannot describe the
+    /// swapped shape.
+    pub fn try_transpose(&self) -> Result<Array<T, D>, ArrayError>
+    where
+        T: Clone,
+    {
+        let dims = self.shape.dims();
+        if dims.len() != 2 {
+            return Err(ArrayError::UnimplementedDimension(format!(
+                "transpose is only defined for 2D arrays, but this array has {} dimensions; \
+                 use permute_axes to reorder the axes instead",
+                dims.len()
+            )));
+        }
+
+        let (rows, cols) = (dims[0], dims[1]);
+        let data = (0..cols)
+            .flat_map(|c| (0..rows).map(move |r| self.data[r * cols + c].clone()))
+            .collect();
+        let raw_dim = self
+            .shape
+            .raw_dim()
+            .with_dims(&[cols, rows])
+            .ok_or_else(|| {
+                ArrayError::UnimplementedDimension(format!(
+                    "transpose needs Dimension::with_dims to build the shape [{}, {}]",
+                    cols, rows
+                ))
+            })?;
+
+        Ok(Array {
+            data,
+            shape: Shape::new(raw_dim),
+        })
+    }
+
+    /// Translates a multi-dimensional index into an offset into the flat row-major data.
+    fn flat_index(&self, index: &[usize]) -> Result<usize, ArrayError> {
+        let dims = self.shape.dims();
+        if index.len() != dims.len() || index.iter().zip(dims).any(|(&i, &d)| i >= d) {
+            return Err(ArrayError::IndexOutOfBounds(format!(
+                "Index {:?} is out of bounds for array with shape {:?}",
+                index, dims
+            )));
+        }
+
+        Ok(index
+            .iter()
+            .zip(dims)
+            .fold(0, |offset, (&i, &d)| offset * d + i))
+    }
+
+    /// Iterates over the elements in row-major order as if the array were broadcast to the shape
+    /// `to`, without materializing the expanded array.
+    ///
+    /// Returns `ArrayError::ShapeMismatch` if the array cannot be broadcast to `to`.
+    pub fn broadcast_iter(&self, to: &[usize]) -> Result<impl Iterator<Item = &T>, ArrayError> {
+        let dims = self.shape.dims();
+        if broadcast_shapes(dims, to).ok().as_deref() != Some(to) {
+            return Err(ArrayError::ShapeMismatch {
+                lhs: dims.to_vec(),
+                rhs: to.to_vec(),
+            });
+        }
+
+        Ok(broadcast_offsets(dims, to).map(|offset| &self.data[offset]))
+    }
+
+    /// Assigns `values[k]` at the multi-dimensional index `indices[k]` for every `k`.
+    ///
+    /// Returns `ArrayError::DimensionMismatch` if the number of indices and values differ, and
+    /// `ArrayError::IndexOutOfBounds` if any index lies outside the array. All indices are
+    /// validated before anything is written, so the array is left untouched on error.
+    pub fn set_at(&mut self, indices: &[Vec<usize>], values: &[T]) -> Result<(), ArrayError>
+    where
+        T: Clone,
+    {
+        if indices.len() != values.len() {
+            return Err(ArrayError::DimensionMismatch {
+                expected: indices.len(),
+                actual: values.len(),
+            });
+        }
+
+        let offsets = indices
+            .iter()
+            .map(|index| self.flat_index(index))
+            .collect::<Result<Vec<usize>, _>>()?;
+        for (offset, value) in offsets.into_iter().zip(values) {
+            self.data[offset] = value.clone();
+        }
+
+        Ok(())
+    }
+
+    /// Replaces every element satisfying `pred` with `value` in place.
+    pub fn set_where<F>(&mut self, pred: F, value: T)
+    where
+        T: Clone,
+        F: Fn(&T) -> bool,
+    {
+        for x in self.data.iter_mut().filter(|x| pred(x)) {
+            *x = value.clone();
+        }
+    }
+
+    /// Returns a 1D array of the elements at the given flat indices into `data()`.
+    ///
+    /// Returns `ArrayError::IndexOutOfBounds` if any index is not smaller than the array size.
+    pub fn gather_flat(&self, indices: &[usize]) -> Result<Array<T, Ix<1>>, ArrayError>
+    where
+        T: Clone,
+    {
+        let data = indices
+            .iter()
+            .map(|&i| {
+                self.data.get(i).cloned().ok_or_else(|| {
+                    ArrayError::IndexOutOfBounds(format!(
+                        "Flat index {} is out of bounds for array of size {}",
+                        i,
+                        self.data.len()
+                    ))
+                })
+            })
+            .collect::<Result<Vec<T>, _>>()?;
+
+        Array::new(data, Shape::new(Ix::<1>::new([indices.len()])))
+    }
+
+    /// Returns the shape of the result of reducing along `axis`: the input shape without that
+    /// axis, or `[1]` when nothing would be left.
+    pub(crate) fn reduced_shape(&self, axis: Option<usize>) -> Shape<IxDyn> {
+        let mut dims = match axis {
+            Some(axis) => {
+                let mut dims = self.shape.dims().to_vec();
+                if axis < dims.len() {
+                    dims.remove(axis);
                 }
+                dims
             }
-            _ => Err(ArrayError::UnimplementedDimension(format!(
-                "Dimension {} for max computation not implemented",
-                ndim
+            None => Vec::new(),
+        };
+        if dims.is_empty() {
+            dims.push(1);
+        }
+        Shape::new(IxDyn::new(&dims))
+    }
+
+    /// Returns the `(start, stride, len)` of every lane through the flat data along `axis`.
+    ///
+    /// Lanes are ordered row-major over the remaining axes, matching the layout produced by the
+    /// axis reductions. With no axis, the whole flattened array forms a single lane.
+    fn lanes(&self, axis: Option<usize>) -> Result<Vec<(usize, usize, usize)>, ArrayError> {
+        let dims = self.shape.dims();
+        let ndim = dims.len();
+
+        match axis {
+            None => Ok(vec![(0, 1, self.data.len())]),
+            Some(axis) if axis >= ndim => Err(ArrayError::InvalidAxis(format!(
+                "Axis {} is out of bounds for array with {} dimensions",
+                axis, ndim
             ))),
+            Some(axis) => {
+                let outer: usize = dims[..axis].iter().product();
+                let len = dims[axis];
+                let inner: usize = dims[axis + 1..].iter().product();
+
+                Ok((0..outer)
+                    .flat_map(|o| (0..inner).map(move |i| (o * len * inner + i, inner, len)))
+                    .collect())
+            }
         }
     }
 
-    /// Computes the minimum value(s) of the array along a specified axis or for the whole array.
-    pub fn min_compute(&self, axis: Option<usize>) -> Result<Vec<T>, ArrayError> {
-        if self.data.is_empty() {
-            return Err(ArrayError::EmptyArray);
+    /// Computes the cumulative sum along `axis` (or over the flattened array) accumulating in
+    /// `f64`, which avoids the overflow an integer accumulator would hit on large values.
+    /// The shape of the array is preserved.
+    ///
+    /// Returns `ArrayError::InvalidAxis` if `axis` is out of bounds.
+    pub fn cumsum_f64(&self, axis: Option<usize>) -> Result<Array<f64, D>, ArrayError>
+    where
+        T: AsPrimitive<f64>,
+    {
+        let mut data = vec![0.0; self.data.len()];
+        for (start, stride, len) in self.lanes(axis)? {
+            let mut acc = 0.0;
+            for i in 0..len {
+                let idx = start + i * stride;
+                acc += self.data[idx].as_();
+                data[idx] = acc;
+            }
         }
 
-        let raw_dim = self.shape.raw_dim();
-        let ndim = raw_dim.ndim();
+        Ok(Array {
+            data,
+            shape: self.shape.clone(),
+        })
+    }
 
-        if let Some(axis) = axis {
-            if axis >= ndim {
-                return Err(ArrayError::InvalidAxis(format!(
-                    "Axis {} is out of bounds for array with {} dimensions",
-                    axis, ndim
-                )));
+    /// Computes the running average along `axis` (or over the flattened array), where each
+    /// element of the result is the mean of all elements up to and including that position.
+    /// The shape of the array is preserved.
+    ///
+    /// Returns `ArrayError::InvalidAxis` if `axis` is out of bounds.
+    pub fn cummean(&self, axis: Option<usize>) -> Result<Array<f64, D>, ArrayError>
+    where
+        T: Into<f64> + Copy,
+    {
+        let mut data = vec![0.0; self.data.len()];
+        for (start, stride, len) in self.lanes(axis)? {
+            let mut acc = 0.0;
+            for i in 0..len {
+                let idx = start + i * stride;
+                acc += self.data[idx].into();
+                data[idx] = acc / (i + 1) as f64;
             }
         }
 
-        match ndim {
-            1 => Ok(vec![*self
-                .data
-                .iter()
-                .min_by(|a, b| a.partial_cmp(b).unwrap())
-                .ok_or(ArrayError::EmptyArray)?]),
-            2 => {
-                let rows = raw_dim.dims()[0];
-                let cols = raw_dim.dims()[1];
-
-                if let Some(axis) = axis {
-                    if axis == 0 {
-                        (0..cols)
-                            .map(|col| {
-                                (0..rows)
-                                    .map(|row| self.data[row * cols + col])
-                                    .min_by(|a, b| a.partial_cmp(b).unwrap())
-                                    .ok_or(ArrayError::EmptyArray)
-                            })
-                            .collect::<Result<Vec<T>, _>>()
-                    } else {
-                        (0..rows)
-                            .map(|row| {
-                                self.data[row * cols..(row + 1) * cols]
-                                    .iter()
-                                    .min_by(|a, b| a.partial_cmp(b).unwrap())
-                                    .map(|&v| v)
-                                    .ok_or(ArrayError::EmptyArray)
-                            })
-                            .collect::<Result<Vec<T>, _>>()
-                    }
-                } else {
-                    Ok(vec![*self
-                        .data
-                        .iter()
-                        .min_by(|a, b| a.partial_cmp(b).unwrap())
-                        .ok_or(ArrayError::EmptyArray)?])
-                }
-            }
-            3 => {
-                let depth = raw_dim.dims()[0];
-                let rows = raw_dim.dims()[1];
-                let cols = raw_dim.dims()[2];
-
-                if let Some(axis) = axis {
-                    match axis {
-                        0 => (0..rows * cols)
-                            .map(|i| {
-                                (0..depth)
-                                    .map(|d| self.data[d * rows * cols + i])
-                                    .min_by(|a, b| a.partial_cmp(b).unwrap())
-                                    .ok_or(ArrayError::EmptyArray)
-                            })
-                            .collect::<Result<Vec<T>, _>>(),
-                        1 => (0..depth)
-                            .flat_map(|d| {
-                                (0..cols).map(move |c| {
-                                    (0..rows)
-                                        .map(|r| self.data[d * rows * cols + r * cols + c])
-                                        .min_by(|a, b| a.partial_cmp(b).unwrap())
-                                        .ok_or(ArrayError::EmptyArray)
-                                })
-                            })
-                            .collect::<Result<Vec<T>, _>>(),
-                        2 => (0..depth)
-                            .flat_map(|d| {
-                                (0..rows).map(move |r| {
-                                    let row_start = d * rows * cols + r * cols;
-                                    self.data[row_start..row_start + cols]
-                                        .iter()
-                                        .min_by(|a, b| a.partial_cmp(b).unwrap())
-                                        .map(|&v| v)
-                                        .ok_or(ArrayError::EmptyArray)
-                                })
-                            })
-                            .collect::<Result<Vec<T>, _>>(),
-                        _ => unreachable!(),
-                    }
-                } else {
-                    Ok(vec![*self
-                        .data
-                        .iter()
-                        .min_by(|a, b| a.partial_cmp(b).unwrap())
-                        .ok_or(ArrayError::EmptyArray)?])
-                }
+        Ok(Array {
+            data,
+            shape: self.shape.clone(),
+        })
+    }
+
+    /// Computes the running population variance along `axis` (or over the flattened array),
+    /// where each element of the result is the variance of all elements up to and including that
+    /// position. Uses Welford's online algorithm, which avoids the cancellation of the naive
+    /// sum-of-squares formula. The shape of the array is preserved.
+    ///
+    /// Returns `ArrayError::InvalidAxis` if `axis` is out of bounds.
+    pub fn cumvar(&self, axis: Option<usize>) -> Result<Array<f64, D>, ArrayError>
+    where
+        T: Into<f64> + Copy,
+    {
+        let mut data = vec![0.0; self.data.len()];
+        for (start, stride, len) in self.lanes(axis)? {
+            let mut mean = 0.0;
+            let mut m2 = 0.0;
+            for i in 0..len {
+                let idx = start + i * stride;
+                let x: f64 = self.data[idx].into();
+                let delta = x - mean;
+                mean += delta / (i + 1) as f64;
+                m2 += delta * (x - mean);
+                data[idx] = m2 / (i + 1) as f64;
             }
-            _ => Err(ArrayError::UnimplementedDimension(format!(
-                "Dimension {} for min computation not implemented",
-                ndim
-            ))),
         }
+
+        Ok(Array {
+            data,
+            shape: self.shape.clone(),
+        })
     }
 
-    /// Computes the mean value(s) of the array along a specified axis or for the whole array.
-    pub fn mean_compute(&self, axis: Option<usize>) -> Result<Vec<f64>, ArrayError>
+    /// Computes the sum of the squared elements along `axis`, or over the whole array.
+    ///
+    /// Returns `ArrayError::EmptyArray` if any dimension is zero and `ArrayError::InvalidAxis`
+    /// if `axis` is out of bounds.
+    pub fn sum_of_squares(&self, axis: Option<usize>) -> Result<Vec<f64>, ArrayError>
     where
-        T: Into<f64>
+        T: Into<f64> + Copy,
     {
         if self.data.is_empty() {
             return Err(ArrayError::EmptyArray);
         }
 
-        let raw_dim = self.shape.raw_dim();
-        let ndim = raw_dim.ndim();
+        Ok(self
+            .lanes(axis)?
+            .into_iter()
+            .map(|(start, stride, len)| {
+                (0..len)
+                    .map(|i| {
+                        let x: f64 = self.data[start + i * stride].into();
+                        x * x
+                    })
+                    .sum()
+            })
+            .collect())
+    }
 
-        if let Some(axis) = axis {
-            if axis >= ndim {
-                return Err(ArrayError::InvalidAxis(format!(
-                    "Axis {} is out of bounds for array with {} dimensions",
-                    axis, ndim
-                )));
-            }
+    /// Computes the root-mean-square of the elements along `axis`, or over the whole array.
+    ///
+    /// Like `mean_compute`, returns `ArrayError::EmptyArray` if any dimension is zero and
+    /// `ArrayError::InvalidAxis` if `axis` is out of bounds.
+    pub fn rms(&self, axis: Option<usize>) -> Result<Vec<f64>, ArrayError>
+    where
+        T: Into<f64> + Copy,
+    {
+        let lanes = self.lanes(axis)?;
+        Ok(self
+            .sum_of_squares(axis)?
+            .into_iter()
+            .zip(lanes)
+            .map(|(sum, (_, _, len))| (sum / len as f64).sqrt())
+            .collect())
+    }
+
+    /// Computes the softmax along `axis`, so that every lane of the result sums to one.
+    ///
+    /// The maximum of each lane is subtracted before exponentiating, which keeps the
+    /// computation numerically stable for large inputs.
+    pub fn softmax(&self, axis: usize) -> Result<Array<f64, D>, ArrayError>
+    where
+        T: Into<f64> + Copy,
+    {
+        if self.data.is_empty() {
+            return Err(ArrayError::EmptyArray);
         }
 
-        match ndim {
-            1 => {
-                let sum: f64 = self.data.iter().map(|&x| Into::<f64>::into(x)).sum();
-                Ok(vec![sum / self.data.len() as f64])
-            }
-            2 => {
-                let rows = raw_dim.dims()[0];
-                let cols = raw_dim.dims()[1];
-
-                if let Some(axis) = axis {
-                    if axis == 0 {
-                        (0..cols)
-                            .map(|col| {
-                                let sum: f64 = (0..rows)
-                                    .map(|row| Into::<f64>::into(self.data[row * cols + col]))
-                                    .sum();
-                                Ok(sum / rows as f64)
-                            })
-                            .collect()
-                    } else {
-                        (0..rows)
-                            .map(|row| {
-                                let sum: f64 = self.data[row * cols..(row + 1) * cols]
-                                    .iter()
-                                    .map(|&x| Into::<f64>::into(x))
-                                    .sum();
-                                Ok(sum / cols as f64)
-                            })
-                            .collect()
-                    }
-                } else {
-                    let sum: f64 = self.data.iter().map(|&x| Into::<f64>::into(x)).sum();
-                    Ok(vec![sum / (rows * cols) as f64])
-                }
+        let mut data = vec![0.0; self.data.len()];
+        for (start, stride, len) in self.lanes(Some(axis))? {
+            let lane_max = (0..len)
+                .map(|i| self.data[start + i * stride].into())
+                .fold(f64::NEG_INFINITY, f64::max);
+
+            let mut total = 0.0;
+            for i in 0..len {
+                let idx = start + i * stride;
+                let exp = (self.data[idx].into() - lane_max).exp();
+                data[idx] = exp;
+                total += exp;
             }
-            3 => {
-                let depth = raw_dim.dims()[0];
-                let rows = raw_dim.dims()[1];
-                let cols = raw_dim.dims()[2];
-
-                if let Some(axis) = axis {
-                    match axis {
-                        0 => (0..rows * cols)
-                            .map(|i| {
-                                let sum: f64 = (0..depth)
-                                    .map(|d| Into::<f64>::into(self.data[d * rows * cols + i]))
-                                    .sum();
-                                Ok(sum / depth as f64)
-                            })
-                            .collect(),
-                        1 => (0..depth)
-                            .flat_map(|d| {
-                                (0..cols).map(move |c| {
-                                    let sum: f64 = (0..rows)
-                                        .map(|r| Into::<f64>::into(self.data[d * rows * cols + r * cols + c]))
-                                        .sum();
-                                    Ok(sum / rows as f64)
-                                })
-                            })
-                            .collect(),
-                        2 => (0..depth)
-                            .flat_map(|d| {
-                                (0..rows).map(move |r| {
-                                    let row_start = d * rows * cols + r * cols;
-                                    let sum: f64 = self.data[row_start..row_start + cols]
-                                        .iter()
-                                        .map(|&x| Into::<f64>::into(x))
-                                        .sum();
-                                    Ok(sum / cols as f64)
-                                })
-                            })
-                            .collect(),
-                        _ => unreachable!(),
-                    }
-                } else {
-                    let sum: f64 = self.data.iter().map(|&x| Into::<f64>::into(x)).sum();
-                    Ok(vec![sum / (depth * rows * cols) as f64])
-                }
+            for i in 0..len {
+                data[start + i * stride] /= total;
             }
-            _ => Err(ArrayError::UnimplementedDimension(format!(
-                "Dimension {} for mean computation not implemented",
-                ndim
-            ))),
         }
-    }
-}
 
-#[cfg(test)]
-mod tests {
-    use std::f64::consts::{E, PI, TAU};
+        Ok(Array {
+            data,
+            shape: self.shape.clone(),
+        })
+    }
 
-    use crate::{Dimension, Ix, Shape};
+    /// Returns a new array with every element bounded to the range `[min, max]`.
+    ///
+    /// Values below `min` become `min` and values above `max` become `max`. Elements that do not
+    /// compare with the bounds, such as `f64::NAN`, are left untouched rather than clamped.
+    /// Returns `ArrayError::InvalidArgument` if `min` is greater than `max`.
+    pub fn clip(&self, min: T, max: T) -> Result<Array<T, D>, ArrayError>
+    where
+        T: PartialOrd + Copy + Debug,
+    {
+        check_clip_bounds(&min, &max)?;
+        Ok(self.map(|x| clip_value(x, min, max)))
+    }
 
-    fn round_to_3dp(value: f64) -> f64 {
-        (value * 1000.0).round() / 1000.0
+    /// Alias for `clip`, matching the naming of `Ord::clamp`.
+    pub fn clamp(&self, min: T, max: T) -> Result<Array<T, D>, ArrayError>
+    where
+        T: PartialOrd + Copy + Debug,
+    {
+        self.clip(min, max)
     }
 
-    fn assert_vec_approx_eq(actual: Vec<f64>, expected: Vec<f64>) {
-        assert_eq!(actual.len(), expected.len(), "Vectors have different lengths");
-        for (a, e) in actual.iter().zip(expected.iter()) {
-            assert_eq!(round_to_3dp(*a), round_to_3dp(*e), "Values differ: {} != {}", a, e);
+    /// Bounds every element to the range `[min, max]` in place, following the same rules as
+    /// `clip`. The array is left untouched if `min` is greater than `max`.
+    pub fn clip_assign(&mut self, min: T, max: T) -> Result<(), ArrayError>
+    where
+        T: PartialOrd + Copy + Debug,
+    {
+        check_clip_bounds(&min, &max)?;
+        for x in self.data.iter_mut() {
+            *x = clip_value(*x, min, max);
         }
+        Ok(())
     }
 
-    #[test]
-    fn array_creation_i64_1d() {
-        let arr = arr![1, 2, 3, 4];
-        let ix = Ix::<1>::new([4]);
-        let shape = Shape::new(ix);
+    /// Returns a new array with every element bounded by the matching elements of `lo` and `hi`.
+    ///
+    /// Like `clip`, elements that do not compare with their bounds (such as NaN) are left
+    /// untouched. Returns `ArrayError::ShapeMismatch` naming both shapes if a bound array does not
+    /// have the same shape as this array, and `ArrayError::InvalidArgument` if any lower bound
+    /// exceeds its upper bound.
+    pub fn clip_arrays(&self, lo: &Array<T, D>, hi: &Array<T, D>) -> Result<Array<T, D>, ArrayError>
+    where
+        T: PartialOrd + Copy + Debug,
+    {
+        for bound in [lo, hi] {
+            if bound.shape.dims() != self.shape.dims() {
+                return Err(ArrayError::ShapeMismatch {
+                    lhs: self.shape.dims().to_vec(),
+                    rhs: bound.shape.dims().to_vec(),
+                });
+            }
+        }
 
-        assert_eq!(arr.shape().raw_dim().size(), 4);
-        assert_eq!(arr.shape().raw_dim().ndim(), 1);
-        assert_eq!(format!("{:?}", arr.shape()), format!("{:?}", shape));
+        let data = self
+            .data
+            .iter()
+            .zip(lo.data.iter().zip(&hi.data))
+            .map(|(&x, (&min, &max))| {
+                check_clip_bounds(&min, &max)?;
+                Ok(clip_value(x, min, max))
+            })
+            .collect::<Result<Vec<T>, _>>()?;
+
+        Ok(Array {
+            data,
+            shape: self.shape.clone(),
+        })
     }
 
-    #[test]
-    fn array_creation_i64_2d() {
-        let arr = arr![[1, 2], [3, 4], [5, 6]];
-        let ix = Ix::<2>::new([3, 2]);
-        let shape = Shape::new(ix);
+    /// Casts every element to type `U` using `as` conversion semantics.
+    /// The shape of the array is preserved.
+    pub fn astype<U>(&self) -> Array<U, D>
+    where
+        T: AsPrimitive<U>,
+        U: Copy + 'static,
+    {
+        self.map(|x| x.as_())
+    }
+}
 
-        assert_eq!(arr.shape().raw_dim().size(), 6);
-        assert_eq!(arr.shape().raw_dim().ndim(), 2);
+impl<T: Copy, D: Dimension> Array<T, D> {
+    /// Returns `ArrayError::ShapeMismatch` unless `other` has exactly the same shape.
+    fn check_same_shape(&self, other: &Array<T, D>) -> Result<(), ArrayError> {
+        if self.shape.dims() != other.shape.dims() {
+            return Err(self.shape_mismatch(other));
+        }
+        Ok(())
+    }
+
+    /// Builds the `ArrayError::ShapeMismatch` describing `self` and `other`.
+    fn shape_mismatch(&self, other: &Array<T, D>) -> ArrayError {
+        ArrayError::ShapeMismatch {
+            lhs: self.shape.dims().to_vec(),
+            rhs: other.shape.dims().to_vec(),
+        }
+    }
+
+    /// Combines `self` and `other` element-wise with `f`, broadcasting the shapes against each
+    /// other like NumPy, and returns a new array of the broadcast shape.
+    ///
+    /// Returns `ArrayError::ShapeMismatch` if the shapes cannot be broadcast together.
+    fn zip_with(
+        &self,
+        other: &Array<T, D>,
+        f: impl Fn(T, T) -> T,
+    ) -> Result<Array<T, D>, ArrayError> {
+        if self.shape.dims() == other.shape.dims() {
+            return Ok(Array {
+                data: self
+                    .data
+                    .iter()
+                    .zip(&other.data)
+                    .map(|(&a, &b)| f(a, b))
+                    .collect(),
+                shape: self.shape.clone(),
+            });
+        }
+
+        let dims = broadcast_shapes(self.shape.dims(), other.shape.dims())
+            .map_err(|_| self.shape_mismatch(other))?;
+        let raw_dim = self
+            .shape
+            .raw_dim()
+            .with_dims(&dims)
+            .ok_or_else(|| self.shape_mismatch(other))?;
+
+        let lhs = broadcast_offsets(self.shape.dims(), &dims);
+        let rhs = broadcast_offsets(other.shape.dims(), &dims);
+        Ok(Array {
+            data: lhs
+                .zip(rhs)
+                .map(|(a, b)| f(self.data[a], other.data[b]))
+                .collect(),
+            shape: Shape::new(raw_dim),
+        })
+    }
+
+    /// Adds `other` element-wise, returning a new array.
+    ///
+    /// The shapes are broadcast against each other, so a `[3, 1]` and a `[1, 3]` array produce a
+    /// `[3, 3]` array. Returns `ArrayError::ShapeMismatch` if they cannot be broadcast together.
+    /// The `+` operator performs the same operation but panics on incompatible shapes.
+    pub fn try_add(&self, other: &Array<T, D>) -> Result<Array<T, D>, ArrayError>
+    where
+        T: Add<Output = T>,
+    {
+        self.zip_with(other, |a, b| a + b)
+    }
+
+    /// Subtracts `other` element-wise, returning a new array.
+    ///
+    /// The shapes are broadcast against each other like in `try_add`. Returns
+    /// `ArrayError::ShapeMismatch` if they cannot be broadcast together. The `-` operator
+    /// performs the same operation but panics on incompatible shapes.
+    pub fn try_sub(&self, other: &Array<T, D>) -> Result<Array<T, D>, ArrayError>
+    where
+        T: Sub<Output = T>,
+    {
+        self.zip_with(other, |a, b| a - b)
+    }
+
+    /// Multiplies by `other` element-wise, returning a new array.
+    ///
+    /// The shapes are broadcast against each other like in `try_add`. Returns
+    /// `ArrayError::ShapeMismatch` if they cannot be broadcast together. The `*` operator
+    /// performs the same operation but panics on incompatible shapes.
+    pub fn try_mul(&self, other: &Array<T, D>) -> Result<Array<T, D>, ArrayError>
+    where
+        T: Mul<Output = T>,
+    {
+        self.zip_with(other, |a, b| a * b)
+    }
+
+    /// Divides by `other` element-wise, returning a new array.
+    ///
+    /// The shapes are broadcast against each other like in `try_add`. Returns
+    /// `ArrayError::ShapeMismatch` if they cannot be broadcast together. The `/` operator
+    /// performs the same operation but panics on incompatible shapes.
+    pub fn try_div(&self, other: &Array<T, D>) -> Result<Array<T, D>, ArrayError>
+    where
+        T: Div<Output = T>,
+    {
+        self.zip_with(other, |a, b| a / b)
+    }
+
+    /// Adds the scalar `s` to every element, returning a new array.
+    pub fn add_scalar(&self, s: T) -> Array<T, D>
+    where
+        T: Add<Output = T>,
+    {
+        self.map(|x| x + s)
+    }
+
+    /// Subtracts the scalar `s` from every element, returning a new array.
+    pub fn sub_scalar(&self, s: T) -> Array<T, D>
+    where
+        T: Sub<Output = T>,
+    {
+        self.map(|x| x - s)
+    }
+
+    /// Multiplies every element by the scalar `s`, returning a new array.
+    pub fn mul_scalar(&self, s: T) -> Array<T, D>
+    where
+        T: Mul<Output = T>,
+    {
+        self.map(|x| x * s)
+    }
+
+    /// Divides every element by the scalar `s`, returning a new array.
+    pub fn div_scalar(&self, s: T) -> Array<T, D>
+    where
+        T: Div<Output = T>,
+    {
+        self.map(|x| x / s)
+    }
+
+    /// Multiplies every element by the scalar `s` in place.
+    pub fn scale_assign(&mut self, s: T)
+    where
+        T: Mul<Output = T>,
+    {
+        for x in self.data.iter_mut() {
+            *x = *x * s;
+        }
+    }
+
+    /// Raises every element to the floating-point power `exp`, promoting the elements to `f64`.
+    /// The shape of the array is preserved.
+    pub fn powf(&self, exp: f64) -> Array<f64, D>
+    where
+        T: Into<f64>,
+    {
+        self.map(|x| x.into().powf(exp))
+    }
+
+    /// Raises every element to the non-negative integer power `exp` by repeated multiplication,
+    /// keeping the element type. The shape of the array is preserved.
+    pub fn powi(&self, exp: u32) -> Array<T, D>
+    where
+        T: One + Mul<Output = T>,
+    {
+        self.map(|x| num_traits::pow(x, exp as usize))
+    }
+
+    /// Adds `other` element-wise in place.
+    ///
+    /// Returns `ArrayError::ShapeMismatch` if the shapes of the arrays differ, leaving `self`
+    /// untouched.
+    pub fn try_add_assign(&mut self, other: &Array<T, D>) -> Result<(), ArrayError>
+    where
+        T: Add<Output = T>,
+    {
+        self.check_same_shape(other)?;
+
+        for (a, &b) in self.data.iter_mut().zip(&other.data) {
+            *a = *a + b;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Zero + One + Copy, D: Dimension> Array<T, D> {
+    /// Replaces all elements in the array with zeros using num_traits::Zero.
+    /// The shape and dimension of the array are preserved.
+    pub fn zeros(&mut self) {
+        let zero = T::zero(); // Use Zero::zero() instead of T::default()
+        self.data.iter_mut().for_each(|x| *x = zero);
+    }
+
+    /// Replaces all elements in the array with ones using num_traits::One.
+    /// The shape and dimension of the array are preserved.
+    pub fn ones(&mut self) {
+        let one = T::one(); // Use One::one() to get the one value
+        self.data.iter_mut().for_each(|x| *x = one);
+    }
+}
+
+impl<T: Zero + One + Clone, D: Dimension> Array<T, D> {
+    /// Replaces all elements in the array with zeros, cloning the zero value into each slot.
+    /// Unlike `zeros`, this works for element types that are `Clone` but not `Copy`.
+    pub fn fill_zeros(&mut self) {
+        let zero = T::zero();
+        self.data.iter_mut().for_each(|x| *x = zero.clone());
+    }
+
+    /// Replaces all elements in the array with ones, cloning the one value into each slot.
+    /// Unlike `ones`, this works for element types that are `Clone` but not `Copy`.
+    pub fn fill_ones(&mut self) {
+        let one = T::one();
+        self.data.iter_mut().for_each(|x| *x = one.clone());
+    }
+}
+
+impl<D: Dimension> Array<i64, D> {
+    /// Returns the data type string for an array of `i64`.
+    pub fn dtype(&self) -> &'static str {
+        "int64"
+    }
+}
+
+impl<D: Dimension> Array<bool, D> {
+    /// Tests whether every element along `axis`, or in the whole array, is `true`.
+    ///
+    /// Each lane stops scanning at its first `false`. Unlike NumPy, where `all([])` is `true`,
+    /// an empty array returns `ArrayError::EmptyArray`.
+    pub fn all(&self, axis: Option<usize>) -> Result<Vec<bool>, ArrayError> {
+        if self.data.is_empty() {
+            return Err(ArrayError::EmptyArray);
+        }
+
+        Ok(self
+            .lanes(axis)?
+            .into_iter()
+            .map(|(start, stride, len)| (0..len).all(|i| self.data[start + i * stride]))
+            .collect())
+    }
+
+    /// Tests whether any element along `axis`, or in the whole array, is `true`.
+    ///
+    /// Each lane stops scanning at its first `true`. Unlike NumPy, where `any([])` is `false`,
+    /// an empty array returns `ArrayError::EmptyArray`.
+    pub fn any(&self, axis: Option<usize>) -> Result<Vec<bool>, ArrayError> {
+        if self.data.is_empty() {
+            return Err(ArrayError::EmptyArray);
+        }
+
+        Ok(self
+            .lanes(axis)?
+            .into_iter()
+            .map(|(start, stride, len)| (0..len).any(|i| self.data[start + i * stride]))
+            .collect())
+    }
+}
+
+impl<D: Dimension> Array<f64, D> {
+    /// Returns the data type string for an array of `f64`.
+    pub fn dtype(&self) -> &'static str {
+        "float64"
+    }
+
+    /// Computes the entropy `-sum(p * log_base(p))` of the probabilities along `axis`, or over
+    /// the whole array. Zero probabilities contribute nothing and are skipped.
+    ///
+    /// Returns `ArrayError::DataTypeMismatch` if any value is negative, and
+    /// `ArrayError::InvalidArgument` if `base` is not a positive number other than one.
+    pub fn entropy(&self, axis: Option<usize>, base: f64) -> Result<Vec<f64>, ArrayError> {
+        if self.data.is_empty() {
+            return Err(ArrayError::EmptyArray);
+        }
+        if base <= 0.0 || base == 1.0 || base.is_nan() {
+            return Err(ArrayError::InvalidArgument(format!(
+                "Logarithm base {} must be positive and different from 1",
+                base
+            )));
+        }
+        if let Some(p) = self.data.iter().find(|&&p| p < 0.0) {
+            return Err(ArrayError::DataTypeMismatch(format!(
+                "Probabilities must be non-negative, found {}",
+                p
+            )));
+        }
+
+        let log_base = base.ln();
+        Ok(self
+            .lanes(axis)?
+            .into_iter()
+            .map(|(start, stride, len)| {
+                -(0..len)
+                    .map(|i| self.data[start + i * stride])
+                    .filter(|&p| p > 0.0)
+                    .map(|p| p * p.ln() / log_base)
+                    .sum::<f64>()
+            })
+            .collect())
+    }
+
+    /// Applies `f` to every element, returning a new array of the same shape.
+    fn map_f64(&self, f: fn(f64) -> f64) -> Array<f64, D> {
+        self.map(f)
+    }
+
+    /// Computes the square root of every element.
+    ///
+    /// Negative elements produce NaN, consistent with `f64::sqrt`, rather than an error.
+    pub fn sqrt(&self) -> Array<f64, D> {
+        self.map_f64(f64::sqrt)
+    }
+
+    /// Computes `e^x` for every element.
+    pub fn exp(&self) -> Array<f64, D> {
+        self.map_f64(f64::exp)
+    }
+
+    /// Computes the natural logarithm of every element.
+    pub fn ln(&self) -> Array<f64, D> {
+        self.map_f64(f64::ln)
+    }
+
+    /// Computes the base-10 logarithm of every element.
+    pub fn log10(&self) -> Array<f64, D> {
+        self.map_f64(f64::log10)
+    }
+
+    /// Computes the sine of every element, in radians.
+    pub fn sin(&self) -> Array<f64, D> {
+        self.map_f64(f64::sin)
+    }
+
+    /// Computes the cosine of every element, in radians.
+    pub fn cos(&self) -> Array<f64, D> {
+        self.map_f64(f64::cos)
+    }
+
+    /// Computes the tangent of every element, in radians.
+    pub fn tan(&self) -> Array<f64, D> {
+        self.map_f64(f64::tan)
+    }
+
+    /// Counts the NaN elements of every lane along `axis`, or of the whole array.
+    ///
+    /// Returns `ArrayError::InvalidAxis` if `axis` is out of bounds.
+    pub fn count_nan(&self, axis: Option<usize>) -> Result<Vec<usize>, ArrayError> {
+        Ok(self
+            .lanes(axis)?
+            .into_iter()
+            .map(|(start, stride, len)| {
+                (0..len)
+                    .filter(|&i| self.data[start + i * stride].is_nan())
+                    .count()
+            })
+            .collect())
+    }
+
+    /// Returns whether every element lies within `atol` of `value`.
+    ///
+    /// NaN elements never compare close, and an empty array is vacuously close to any value.
+    pub fn all_close_to(&self, value: f64, atol: f64) -> bool {
+        self.data.iter().all(|&x| (x - value).abs() <= atol)
+    }
+
+    /// Computes a histogram of all elements using `bins` equal-width bins spanning the range of
+    /// the data, returning the count per bin and the `bins + 1` bin edges.
+    ///
+    /// Like NumPy, every bin is half-open except the last, which also includes the maximum. When
+    /// all elements are equal the range is widened to `[x - 0.5, x + 0.5]`. Returns
+    /// `ArrayError::InvalidArgument` if `bins` is zero and `ArrayError::NanEncountered` if the
+    /// data contains NaN.
+    pub fn histogram(&self, bins: usize) -> Result<(Vec<usize>, Vec<f64>), ArrayError> {
+        if self.data.is_empty() {
+            return Err(ArrayError::EmptyArray);
+        }
+        if bins == 0 {
+            return Err(ArrayError::InvalidArgument(
+                "Number of bins must be at least 1".to_string(),
+            ));
+        }
+        if self.data.iter().any(|x| x.is_nan()) {
+            return Err(ArrayError::NanEncountered(
+                "Cannot compute a histogram of data containing NaN".to_string(),
+            ));
+        }
+
+        let mut lo = self.data.iter().copied().fold(f64::INFINITY, f64::min);
+        let mut hi = self.data.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        if lo == hi {
+            lo -= 0.5;
+            hi += 0.5;
+        }
+
+        let width = (hi - lo) / bins as f64;
+        let edges = (0..=bins).map(|i| lo + i as f64 * width).collect();
+        let mut counts = vec![0; bins];
+        for &x in &self.data {
+            let bin = (((x - lo) / width) as usize).min(bins - 1);
+            counts[bin] += 1;
+        }
+
+        Ok((counts, edges))
+    }
+
+    /// Estimates the mode of continuous data as the center of the most populated bin of a
+    /// `bins`-bin histogram. Ties resolve to the lowest bin.
+    ///
+    /// Returns the same errors as `histogram`.
+    pub fn mode_binned(&self, bins: usize) -> Result<f64, ArrayError> {
+        let (counts, edges) = self.histogram(bins)?;
+        let mut best = 0;
+        for (i, &count) in counts.iter().enumerate() {
+            if count > counts[best] {
+                best = i;
+            }
+        }
+
+        Ok((edges[best] + edges[best + 1]) / 2.0)
+    }
+}
+
+impl<D: Dimension> Array<f32, D> {
+    /// Computes the mean along `axis`, or over the whole array, accumulating in `f32`.
+    ///
+    /// Unlike `mean_compute`, no `f64` intermediate is allocated or returned, which keeps memory
+    /// use down for large single-precision arrays at the cost of some accumulated rounding.
+    pub fn mean_f32(&self, axis: Option<usize>) -> Result<Vec<f32>, ArrayError> {
+        if self.data.is_empty() {
+            return Err(ArrayError::EmptyArray);
+        }
+
+        Ok(self
+            .lanes(axis)?
+            .into_iter()
+            .map(|(start, stride, len)| {
+                let sum: f32 = (0..len).map(|i| self.data[start + i * stride]).sum();
+                sum / len as f32
+            })
+            .collect())
+    }
+}
+
+impl<T: Zero + Copy> Array<T, Ix<1>> {
+    /// Builds an `n×n` matrix with this vector on the main diagonal and zeros elsewhere.
+    pub fn diagflat(&self) -> Array<T, Ix<2>> {
+        let n = self.data.len();
+        let mut data = vec![T::zero(); n * n];
+        for (i, &value) in self.data.iter().enumerate() {
+            data[i * n + i] = value;
+        }
+
+        Array {
+            data,
+            shape: Shape::new(Ix::<2>::new([n, n])),
+        }
+    }
+
+    /// Computes the inner product with `other`, accumulating in `T`.
+    ///
+    /// Returns `ArrayError::DimensionMismatch` if the lengths differ. For integer inputs that may
+    /// overflow, use `dot_f64` instead.
+    pub fn dot(&self, other: &Array<T, Ix<1>>) -> Result<T, ArrayError>
+    where
+        T: Add<Output = T> + Mul<Output = T>,
+    {
+        if self.data.len() != other.data.len() {
+            return Err(ArrayError::DimensionMismatch {
+                expected: self.data.len(),
+                actual: other.data.len(),
+            });
+        }
+
+        Ok(self
+            .data
+            .iter()
+            .zip(other.data.iter())
+            .fold(T::zero(), |acc, (&a, &b)| acc + a * b))
+    }
+}
+
+impl<T: AsPrimitive<f64>> Array<T, Ix<1>> {
+    /// Computes the inner product with `other`, accumulating the products in `f64`.
+    ///
+    /// This avoids the overflow an integer accumulator would hit on large inputs.
+    /// Returns `ArrayError::DimensionMismatch` if the lengths differ.
+    pub fn dot_f64(&self, other: &Array<T, Ix<1>>) -> Result<f64, ArrayError> {
+        if self.data.len() != other.data.len() {
+            return Err(ArrayError::DimensionMismatch {
+                expected: self.data.len(),
+                actual: other.data.len(),
+            });
+        }
+
+        Ok(self
+            .data
+            .iter()
+            .zip(other.data.iter())
+            .map(|(&a, &b)| a.as_() * b.as_())
+            .sum())
+    }
+}
+
+impl<T: AsPrimitive<f64>> Array<T, Ix<2>> {
+    /// Computes the matrix product with `other`, accumulating the products in `f64`.
+    ///
+    /// `self` must be `[m, k]` and `other` `[k, n]`, producing an `[m, n]` array. Returns
+    /// `ArrayError::DimensionMismatch` if the inner dimensions differ, with the column count of
+    /// `self` as `expected` and the row count of `other` as `actual`.
+    pub fn matmul_f64(&self, other: &Array<T, Ix<2>>) -> Result<Array<f64, Ix<2>>, ArrayError> {
+        let (m, k) = (self.shape.dims()[0], self.shape.dims()[1]);
+        let (k2, n) = (other.shape.dims()[0], other.shape.dims()[1]);
+        if k != k2 {
+            return Err(ArrayError::DimensionMismatch {
+                expected: k,
+                actual: k2,
+            });
+        }
+
+        let mut data = vec![0.0; m * n];
+        for i in 0..m {
+            for p in 0..k {
+                let a: f64 = self.data[i * k + p].as_();
+                for j in 0..n {
+                    data[i * n + j] += a * other.data[p * n + j].as_();
+                }
+            }
+        }
+
+        Array::new(data, Shape::new(Ix::<2>::new([m, n])))
+    }
+}
+
+/// Strategy used by `Array::pad` to fill the border around an array.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PadMode<T> {
+    /// Fills the border with a constant value.
+    Constant(T),
+    /// Repeats the value at the edge of each axis.
+    Edge,
+    /// Mirrors the values next to the edge of each axis, without repeating the edge itself.
+    Reflect,
+}
+
+impl<T, const N: usize> Array<T, Ix<N>> {
+    /// Returns a reference to the element at `index`, or `None` if any coordinate is out of range.
+    pub fn get(&self, index: [usize; N]) -> Option<&T> {
+        let offset = self.flat_index(&index).ok()?;
+        self.data.get(offset)
+    }
+
+    /// Returns a mutable reference to the element at `index`, or `None` if any coordinate is out
+    /// of range.
+    pub fn get_mut(&mut self, index: [usize; N]) -> Option<&mut T> {
+        let offset = self.flat_index(&index).ok()?;
+        self.data.get_mut(offset)
+    }
+
+    /// Returns an iterator over the elements in row-major order, each paired with its
+    /// multi-dimensional index.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = ([usize; N], &T)> {
+        let dims: [usize; N] = self.shape.dims().try_into().unwrap();
+        self.data.iter().enumerate().map(move |(flat, value)| {
+            let mut index = [0; N];
+            let mut remainder = flat;
+            for axis in (0..N).rev() {
+                index[axis] = remainder % dims[axis];
+                remainder /= dims[axis];
+            }
+            (index, value)
+        })
+    }
+
+    /// Writes `value` at `index`.
+    ///
+    /// Returns `ArrayError::IndexOutOfBounds` naming the index and the shape if any coordinate is
+    /// out of range, leaving the array untouched.
+    pub fn set(&mut self, index: [usize; N], value: T) -> Result<(), ArrayError> {
+        let offset = self.flat_index(&index)?;
+        self.data[offset] = value;
+        Ok(())
+    }
+
+    /// Returns a reference to the element at `index`.
+    ///
+    /// Panics with the `ArrayError::IndexOutOfBounds` message if any coordinate is out of range.
+    pub fn at(&self, index: [usize; N]) -> &T {
+        match self.flat_index(&index) {
+            Ok(offset) => &self.data[offset],
+            Err(err) => panic!("{}", err),
+        }
+    }
+}
+
+impl<T: Clone, const N: usize> Array<T, Ix<N>> {
+    /// Creates an array of shape `dims` with every element set to `value`.
+    ///
+    /// Returns `ArrayError::DimensionMismatch` if `dims` does not have exactly `N` entries.
+    pub fn from_elem(dims: &[usize], value: T) -> Result<Self, ArrayError> {
+        let dims: [usize; N] = dims
+            .try_into()
+            .map_err(|_| ArrayError::DimensionMismatch {
+                expected: N,
+                actual: dims.len(),
+            })?;
+        let dims = Ix::<N>::new(dims);
+        let size = checked_size(&dims)?;
+
+        Array::new(vec![value; size], Shape::new(dims))
+    }
+
+    /// Pads every axis with `width` elements on both sides, filled according to `mode`.
+    ///
+    /// `PadMode::Edge` requires every axis to be non-empty and `PadMode::Reflect` requires `width`
+    /// to be smaller than every axis length; otherwise `ArrayError::InvalidArgument` is returned.
+    pub fn pad(&self, width: usize, mode: PadMode<T>) -> Result<Array<T, Ix<N>>, ArrayError> {
+        let dims = self.shape.dims();
+        for &len in dims {
+            match mode {
+                PadMode::Edge if len == 0 && width > 0 => {
+                    return Err(ArrayError::InvalidArgument(
+                        "Edge padding requires every axis to be non-empty".to_string(),
+                    ));
+                }
+                PadMode::Reflect if width >= len && width > 0 => {
+                    return Err(ArrayError::InvalidArgument(format!(
+                        "Reflect padding width {} must be smaller than axis length {}",
+                        width, len
+                    )));
+                }
+                _ => {}
+            }
+        }
+
+        let mut new_dims = [0; N];
+        for (new_dim, &len) in new_dims.iter_mut().zip(dims) {
+            *new_dim = len + 2 * width;
+        }
+        let size: usize = new_dims.iter().product();
+
+        let mut data = Vec::with_capacity(size);
+        for flat in 0..size {
+            let mut remainder = flat;
+            let mut source = 0;
+            let mut stride = 1;
+            let mut inside = true;
+            for axis in (0..N).rev() {
+                let coord = remainder % new_dims[axis];
+                remainder /= new_dims[axis];
+
+                let len = dims[axis];
+                let source_coord = if coord < width {
+                    match mode {
+                        PadMode::Constant(_) => None,
+                        PadMode::Edge => Some(0),
+                        PadMode::Reflect => Some(width - coord),
+                    }
+                } else if coord - width >= len {
+                    let overshoot = coord - width - len;
+                    match mode {
+                        PadMode::Constant(_) => None,
+                        PadMode::Edge => Some(len - 1),
+                        PadMode::Reflect => Some(len - 2 - overshoot),
+                    }
+                } else {
+                    Some(coord - width)
+                };
+
+                match source_coord {
+                    Some(c) => source += c * stride,
+                    None => inside = false,
+                }
+                stride *= len;
+            }
+
+            match (&mode, inside) {
+                (PadMode::Constant(value), false) => data.push(value.clone()),
+                _ => data.push(self.data[source].clone()),
+            }
+        }
+
+        Array::new(data, Shape::new(Ix::<N>::new(new_dims)))
+    }
+
+    /// Pads the end of every axis with `value` until the array reaches the `target` dimensions.
+    ///
+    /// Returns `ArrayError::DimensionMismatch` if `target` does not have `N` entries or if any
+    /// target dimension is smaller than the current one.
+    pub fn pad_to_shape(&self, target: &[usize], value: T) -> Result<Array<T, Ix<N>>, ArrayError> {
+        if target.len() != N {
+            return Err(ArrayError::DimensionMismatch {
+                expected: N,
+                actual: target.len(),
+            });
+        }
+        let dims = self.shape.dims();
+        if let Some((&dim, &want)) = dims.iter().zip(target).find(|(&dim, &want)| want < dim) {
+            return Err(ArrayError::DimensionMismatch {
+                expected: dim,
+                actual: want,
+            });
+        }
+
+        let mut padded = Array::from_elem(target, value)?;
+        for (flat, x) in self.data.iter().enumerate() {
+            let mut remainder = flat;
+            let mut target_offset = 0;
+            let mut stride = 1;
+            for axis in (0..N).rev() {
+                target_offset += (remainder % dims[axis]) * stride;
+                remainder /= dims[axis];
+                stride *= target[axis];
+            }
+            padded.data[target_offset] = x.clone();
+        }
+
+        Ok(padded)
+    }
+
+    /// Returns a new array with the axes reordered so that axis `i` of the result is axis
+    /// `order[i]` of this array, matching NumPy's `transpose(axes)`. The data is physically
+    /// reordered into the new row-major layout.
+    ///
+    /// Returns `ArrayError::InvalidAxis` if `order` is not a permutation of `0..N`.
+    pub fn permute_axes(&self, order: [usize; N]) -> Result<Array<T, Ix<N>>, ArrayError> {
+        let mut seen = [false; N];
+        for &axis in &order {
+            if axis >= N || seen[axis] {
+                return Err(ArrayError::InvalidAxis(format!(
+                    "Axis order {:?} is not a permutation of the {} axes",
+                    order, N
+                )));
+            }
+            seen[axis] = true;
+        }
+
+        let dims = self.shape.dims();
+        let strides = self.standard_strides();
+        let mut new_dims = [0; N];
+        for (new_dim, &axis) in new_dims.iter_mut().zip(&order) {
+            *new_dim = dims[axis];
+        }
+
+        let data = (0..self.data.len())
+            .map(|flat| {
+                let mut remainder = flat;
+                let mut source = 0;
+                for i in (0..N).rev() {
+                    source += (remainder % new_dims[i]) * strides[order[i]] as usize;
+                    remainder /= new_dims[i];
+                }
+                self.data[source].clone()
+            })
+            .collect();
+
+        Ok(Array {
+            data,
+            shape: Shape::new(Ix::<N>::new(new_dims)),
+        })
+    }
+
+    /// Returns a new array with an axis of length one inserted at position `axis`, the inverse of
+    /// `squeeze`. The data is unchanged.
+    ///
+    /// As with `manipulation::stack`, the output rank is a separate parameter `M` that must equal `N + 1`.
+    /// Returns `ArrayError::DimensionMismatch` if it does not, and `ArrayError::InvalidAxis` if
+    /// `axis` is greater than `N`.
+    pub fn expand_dims<const M: usize>(&self, axis: usize) -> Result<Array<T, Ix<M>>, ArrayError> {
+        if M != N + 1 {
+            return Err(ArrayError::DimensionMismatch {
+                expected: N + 1,
+                actual: M,
+            });
+        }
+        if axis > N {
+            return Err(ArrayError::InvalidAxis(format!(
+                "Axis {} is out of bounds for inserting into an array with {} dimensions",
+                axis, N
+            )));
+        }
+
+        let dims = self.shape.dims();
+        let mut new_dims = [1; M];
+        new_dims[..axis].copy_from_slice(&dims[..axis]);
+        new_dims[axis + 1..].copy_from_slice(&dims[axis..]);
+        Ok(Array {
+            data: self.data.clone(),
+            shape: Shape::new(Ix::<M>::new(new_dims)),
+        })
+    }
+
+    /// Returns an iterator over consecutive sub-arrays of up to `size` slices along `axis`.
+    ///
+    /// Every chunk keeps the other dimensions intact; the last chunk is smaller when the axis
+    /// length is not a multiple of `size`.
+    ///
+    /// Panics if `size` is zero or `axis` is out of bounds.
+    pub fn chunks(&self, size: usize, axis: usize) -> impl Iterator<Item = Array<T, Ix<N>>> + '_ {
+        assert!(size > 0, "chunk size must be non-zero");
+        assert!(
+            axis < N,
+            "Axis {} is out of bounds for array with {} dimensions",
+            axis,
+            N
+        );
+
+        let len = self.shape.dims()[axis];
+        (0..len)
+            .step_by(size)
+            .map(move |start| self.slice_axis_owned(axis, start, size.min(len - start)))
+    }
+
+    /// Splits the array into `sections` equally sized owned arrays along `axis`.
+    ///
+    /// Returns `ArrayError::InvalidAxis` if `axis` is out of bounds,
+    /// `ArrayError::InvalidArgument` if `sections` is zero, and `ArrayError::DimensionMismatch`
+    /// if the axis length is not divisible by `sections`.
+    pub fn split(&self, sections: usize, axis: usize) -> Result<Vec<Array<T, Ix<N>>>, ArrayError> {
+        let len = self.axis_len(axis)?;
+        if sections == 0 {
+            return Err(ArrayError::InvalidArgument(
+                "Number of sections must be at least 1".to_string(),
+            ));
+        }
+        if len % sections != 0 {
+            return Err(ArrayError::DimensionMismatch {
+                expected: len.div_ceil(sections) * sections,
+                actual: len,
+            });
+        }
+
+        let size = len / sections;
+        Ok((0..sections)
+            .map(|i| self.slice_axis_owned(axis, i * size, size))
+            .collect())
+    }
+
+    /// Splits the array into owned arrays along `axis` at the given `indices`, matching NumPy's
+    /// `split` with a list of indices: `[2, 5]` yields the ranges `..2`, `2..5` and `5..`.
+    ///
+    /// Returns `ArrayError::InvalidAxis` if `axis` is out of bounds and
+    /// `ArrayError::IndexOutOfBounds` if the indices are decreasing or exceed the axis length.
+    pub fn split_at(
+        &self,
+        indices: &[usize],
+        axis: usize,
+    ) -> Result<Vec<Array<T, Ix<N>>>, ArrayError> {
+        let len = self.axis_len(axis)?;
+        let mut start = 0;
+        let mut parts = Vec::with_capacity(indices.len() + 1);
+        for &end in indices.iter().chain(std::iter::once(&len)) {
+            if end < start || end > len {
+                return Err(ArrayError::IndexOutOfBounds(format!(
+                    "Split indices {:?} must be non-decreasing and at most {}",
+                    indices, len
+                )));
+            }
+            parts.push(self.slice_axis_owned(axis, start, end - start));
+            start = end;
+        }
+
+        Ok(parts)
+    }
+
+    /// Returns the length of `axis`, or `ArrayError::InvalidAxis` if it is out of bounds.
+    fn axis_len(&self, axis: usize) -> Result<usize, ArrayError> {
+        self.shape.dims().get(axis).copied().ok_or_else(|| {
+            ArrayError::InvalidAxis(format!(
+                "Axis {} is out of bounds for array with {} dimensions",
+                axis, N
+            ))
+        })
+    }
+
+    /// Copies the `count` slices starting at `start` along `axis` into a new array.
+    fn slice_axis_owned(&self, axis: usize, start: usize, count: usize) -> Array<T, Ix<N>> {
+        let dims = self.shape.dims();
+        let outer: usize = dims[..axis].iter().product();
+        let len = dims[axis];
+        let inner: usize = dims[axis + 1..].iter().product();
+
+        let mut data = Vec::with_capacity(outer * count * inner);
+        for o in 0..outer {
+            let from = (o * len + start) * inner;
+            data.extend_from_slice(&self.data[from..from + count * inner]);
+        }
+
+        let mut new_dims = [0; N];
+        new_dims.copy_from_slice(dims);
+        new_dims[axis] = count;
+        Array {
+            data,
+            shape: Shape::new(Ix::<N>::new(new_dims)),
+        }
+    }
+}
+
+impl<T: Copy> Array<T, Ix<2>> {
+    /// Returns a new array with the rows and columns swapped, reordering the data into the new
+    /// row-major layout.
+    pub fn transpose(&self) -> Self {
+        let (rows, cols) = (self.shape.dims()[0], self.shape.dims()[1]);
+        let data = (0..cols)
+            .flat_map(|c| (0..rows).map(move |r| self.data[r * cols + c]))
+            .collect();
+
+        Array {
+            data,
+            shape: Shape::new(Ix::<2>::new([cols, rows])),
+        }
+    }
+
+    /// Returns a transposed view of the array without copying any elements; the short form of
+    /// `transpose` for chaining. Call `to_owned` on the view to obtain the owned transpose.
+    ///
+    /// The view can be fed straight into `matmul`, so `a.t().matmul(&b)` computes `Aᵀ B` without
+    /// materializing the transpose.
+    pub fn t(&self) -> ArrayView<'_, T, Ix<2>> {
+        let (rows, cols) = (self.shape.dims()[0], self.shape.dims()[1]);
+        ArrayView::new(
+            &self.data,
+            0,
+            Shape::new(Ix::<2>::new([cols, rows])),
+            vec![1, cols as isize],
+        )
+    }
+
+    /// Computes the matrix product with `rhs`, which may be an owned array or a view.
+    ///
+    /// `self` must be `[m, k]` and `rhs` `[k, n]`, producing an `[m, n]` array. Returns
+    /// `ArrayError::DimensionMismatch` if the inner dimensions differ; see `ArrayView::matmul`.
+    pub fn matmul<R: AsView<T, Ix<2>>>(&self, rhs: &R) -> Result<Self, ArrayError>
+    where
+        T: Zero + Add<Output = T> + Mul<Output = T>,
+    {
+        self.view().matmul(rhs)
+    }
+
+    /// Sets every element of the main diagonal to `value` in place.
+    ///
+    /// Non-square arrays are supported, filling the first `min(rows, cols)` diagonal entries.
+    pub fn fill_diagonal(&mut self, value: T) -> Result<(), ArrayError> {
+        let (rows, cols) = (self.shape.dims()[0], self.shape.dims()[1]);
+        for i in 0..rows.min(cols) {
+            self.data[i * cols + i] = value;
+        }
+
+        Ok(())
+    }
+
+    /// Builds coordinate matrices from two 1D coordinate vectors, like NumPy's `meshgrid` with
+    /// the default `xy` indexing.
+    ///
+    /// Both returned arrays have shape `[y.len(), x.len()]`; the first repeats `x` along every
+    /// row and the second repeats `y` along every column.
+    pub fn meshgrid(x: &Array<T, Ix<1>>, y: &Array<T, Ix<1>>) -> (Self, Self) {
+        let (nx, ny) = (x.data.len(), y.data.len());
+        let shape = Shape::new(Ix::<2>::new([ny, nx]));
+
+        let xx = (0..ny).flat_map(|_| x.data.iter().copied()).collect();
+        let yy = y
+            .data
+            .iter()
+            .flat_map(|&v| std::iter::repeat_n(v, nx))
+            .collect();
+
+        (
+            Array {
+                data: xx,
+                shape: shape.clone(),
+            },
+            Array { data: yy, shape },
+        )
+    }
+
+    /// Assembles equal-length 1D arrays as the columns of a 2D array.
+    ///
+    /// The result has shape `[len, cols.len()]`. Returns `ArrayError::EmptyArray` if no columns
+    /// are given and `ArrayError::DimensionMismatch` if the column lengths differ.
+    pub fn stack_columns(cols: &[Array<T, Ix<1>>]) -> Result<Self, ArrayError> {
+        let rows = cols.first().ok_or(ArrayError::EmptyArray)?.data.len();
+        if let Some(col) = cols.iter().find(|col| col.data.len() != rows) {
+            return Err(ArrayError::DimensionMismatch {
+                expected: rows,
+                actual: col.data.len(),
+            });
+        }
+
+        let data = (0..rows)
+            .flat_map(|row| cols.iter().map(move |col| col.data[row]))
+            .collect();
+
+        Array::new(data, Shape::new(Ix::<2>::new([rows, cols.len()])))
+    }
+
+    /// Returns a new array where runs of consecutive identical rows are collapsed into one,
+    /// like `Vec::dedup` applied to rows.
+    pub fn dedup_rows(&self) -> Self
+    where
+        T: PartialEq,
+    {
+        let cols = self.shape.dims()[1];
+        let mut data: Vec<T> = Vec::with_capacity(self.data.len());
+        let mut rows = 0;
+
+        for r in 0..self.shape.dims()[0] {
+            let row = &self.data[r * cols..(r + 1) * cols];
+            if rows == 0 || data[data.len() - cols..] != *row {
+                data.extend_from_slice(row);
+                rows += 1;
+            }
+        }
+
+        Array {
+            data,
+            shape: Shape::new(Ix::<2>::new([rows, cols])),
+        }
+    }
+
+    /// Assembles a grid of 2D blocks into a single matrix, like NumPy's `block`.
+    ///
+    /// Each inner `Vec` is one row of blocks; the blocks in a row must have the same number of
+    /// rows and every block row must add up to the same number of columns. Returns
+    /// `ArrayError::DimensionMismatch` if the blocks do not fit together and
+    /// `ArrayError::EmptyArray` if there are no blocks.
+    pub fn block(blocks: &[Vec<Array<T, Ix<2>>>]) -> Result<Self, ArrayError> {
+        if blocks.is_empty() || blocks.iter().any(|row| row.is_empty()) {
+            return Err(ArrayError::EmptyArray);
+        }
+
+        let mut total_cols = None;
+        let mut data = Vec::new();
+        let mut total_rows = 0;
+
+        for block_row in blocks {
+            let rows = block_row[0].shape.dims()[0];
+            if let Some(block) = block_row.iter().find(|b| b.shape.dims()[0] != rows) {
+                return Err(ArrayError::DimensionMismatch {
+                    expected: rows,
+                    actual: block.shape.dims()[0],
+                });
+            }
+
+            let cols: usize = block_row.iter().map(|b| b.shape.dims()[1]).sum();
+            match total_cols {
+                None => total_cols = Some(cols),
+                Some(expected) if expected != cols => {
+                    return Err(ArrayError::DimensionMismatch {
+                        expected,
+                        actual: cols,
+                    });
+                }
+                _ => {}
+            }
+
+            for r in 0..rows {
+                for block in block_row {
+                    let width = block.shape.dims()[1];
+                    data.extend_from_slice(&block.data[r * width..(r + 1) * width]);
+                }
+            }
+            total_rows += rows;
+        }
+
+        Array::new(
+            data,
+            Shape::new(Ix::<2>::new([total_rows, total_cols.unwrap_or(0)])),
+        )
+    }
+}
+
+impl<T, D: Dimension> Array<T, D>
+where
+    T: PartialOrd + Copy,
+{
+    /// Computes the maximum value(s) of the array along a specified axis or for the whole array.
+    ///
+    /// A lane containing NaN yields NaN, like `max_compute_with` under `NanPolicy::Propagate`.
+    pub fn max_compute(&self, axis: Option<usize>) -> Result<Vec<T>, ArrayError> {
+        self.max_compute_with(axis, NanPolicy::Propagate)
+    }
+
+    /// Computes the minimum value(s) of the array along a specified axis or for the whole array.
+    ///
+    /// A lane containing NaN yields NaN, like `min_compute_with` under `NanPolicy::Propagate`.
+    pub fn min_compute(&self, axis: Option<usize>) -> Result<Vec<T>, ArrayError> {
+        self.min_compute_with(axis, NanPolicy::Propagate)
+    }
+
+    /// Computes the mean value(s) of the array along a specified axis or for the whole array.
+    ///
+    /// Returns `ArrayError::EmptyArray` if any dimension is zero, so reducing along a
+    /// zero-length axis never divides by zero. A lane containing NaN yields NaN, like
+    /// `mean_compute_with` under `NanPolicy::Propagate`.
+    pub fn mean_compute(&self, axis: Option<usize>) -> Result<Vec<f64>, ArrayError>
+    where
+        T: Into<f64>,
+    {
+        self.mean_compute_with(axis, NanPolicy::Propagate)
+    }
+
+    /// Finds the element of every lane that `is_better` prefers, together with its index.
+    ///
+    /// For the whole array the index is a flat index into `data()`, while along an axis it is the
+    /// position within each reduced lane. Ties resolve to the first occurrence, and a lane
+    /// containing NaN yields its first NaN and that NaN's index, matching NumPy's `argmax`.
+    fn extreme_with_index(
+        &self,
+        axis: Option<usize>,
+        is_better: impl Fn(&T, &T) -> bool,
+    ) -> Result<(Vec<T>, Vec<usize>), ArrayError> {
+        if self.data.is_empty() {
+            return Err(ArrayError::EmptyArray);
+        }
+
+        let lanes = self.lanes(axis)?;
+        let mut values = Vec::with_capacity(lanes.len());
+        let mut indices = Vec::with_capacity(lanes.len());
+
+        let is_nan = |x: &T| x.partial_cmp(x).is_none();
+        for (start, stride, len) in lanes {
+            let mut best = self.data[start];
+            let mut best_index = 0;
+            for i in 1..len {
+                if is_nan(&best) {
+                    break;
+                }
+                let value = self.data[start + i * stride];
+                if is_nan(&value) || is_better(&value, &best) {
+                    best = value;
+                    best_index = i;
+                }
+            }
+            values.push(best);
+            indices.push(best_index);
+        }
+
+        Ok((values, indices))
+    }
+
+    /// Computes the maximum value(s) together with their indices in a single pass.
+    ///
+    /// For the whole array the index is a flat index into `data()`, while along an axis it is the
+    /// position within each reduced lane. Ties resolve to the first occurrence, and a lane
+    /// containing NaN yields its first NaN, like `max_compute`.
+    pub fn max_with_index(&self, axis: Option<usize>) -> Result<(Vec<T>, Vec<usize>), ArrayError> {
+        self.extreme_with_index(axis, |value, best| value > best)
+    }
+
+    /// Computes the index of the maximum value(s), matching NumPy's `argmax`.
+    ///
+    /// For the whole array a single flat index into `data()` is returned, while along an axis
+    /// the index within each reduced lane is returned. Ties resolve to the first occurrence, and
+    /// a lane containing NaN yields the index of its first NaN.
+    pub fn argmax_compute(&self, axis: Option<usize>) -> Result<Vec<usize>, ArrayError> {
+        Ok(self.max_with_index(axis)?.1)
+    }
+
+    /// Computes the index of the minimum value(s), matching NumPy's `argmin`.
+    ///
+    /// For the whole array a single flat index into `data()` is returned, while along an axis
+    /// the index within each reduced lane is returned. Ties resolve to the first occurrence, and
+    /// a lane containing NaN yields the index of its first NaN. Returns `ArrayError::EmptyArray` for an empty array and `ArrayError::InvalidAxis` for an
+    /// out-of-range axis.
+    pub fn argmin_compute(&self, axis: Option<usize>) -> Result<Vec<usize>, ArrayError> {
+        Ok(self.extreme_with_index(axis, |value, best| value < best)?.1)
+    }
+
+    /// Computes the index of the maximum value(s) like `argmax_compute`, skipping NaN elements,
+    /// matching NumPy's `nanargmax`.
+    ///
+    /// Returns `ArrayError::NanEncountered` if a lane contains only NaN.
+    pub fn nanargmax(&self, axis: Option<usize>) -> Result<Vec<usize>, ArrayError> {
+        self.nan_extreme_index(axis, |value, best| value > best)
+    }
+
+    /// Computes the index of the minimum value(s) like `argmin_compute`, skipping NaN elements,
+    /// matching NumPy's `nanargmin`.
+    ///
+    /// Returns `ArrayError::NanEncountered` if a lane contains only NaN.
+    pub fn nanargmin(&self, axis: Option<usize>) -> Result<Vec<usize>, ArrayError> {
+        self.nan_extreme_index(axis, |value, best| value < best)
+    }
+
+    /// Finds the index of the element of every lane that `is_better` prefers, ignoring NaN
+    /// elements, i.e. those that do not compare with themselves.
+    fn nan_extreme_index(
+        &self,
+        axis: Option<usize>,
+        is_better: impl Fn(&T, &T) -> bool,
+    ) -> Result<Vec<usize>, ArrayError> {
+        if self.data.is_empty() {
+            return Err(ArrayError::EmptyArray);
+        }
+
+        let is_nan = |x: &T| x.partial_cmp(x).is_none();
+        self.lanes(axis)?
+            .into_iter()
+            .map(|(start, stride, len)| {
+                let mut best: Option<(usize, T)> = None;
+                for i in 0..len {
+                    let value = self.data[start + i * stride];
+                    if is_nan(&value) {
+                        continue;
+                    }
+                    if best.is_none_or(|(_, best)| is_better(&value, &best)) {
+                        best = Some((i, value));
+                    }
+                }
+                best.map(|(i, _)| i).ok_or_else(|| {
+                    ArrayError::NanEncountered(format!(
+                        "Lane starting at flat index {} contains only NaN",
+                        start
+                    ))
+                })
+            })
+            .collect()
+    }
+
+    /// Computes the peak-to-peak range `max - min` along a specified axis or for the whole
+    /// array, matching NumPy's `ptp`.
+    ///
+    /// A lane containing NaN yields NaN, as it does for `max_compute` and `min_compute`.
+    pub fn ptp_compute(&self, axis: Option<usize>) -> Result<Vec<T>, ArrayError>
+    where
+        T: Sub<Output = T>,
+    {
+        let max = self.max_compute(axis)?;
+        let min = self.min_compute(axis)?;
+
+        Ok(max.into_iter().zip(min).map(|(hi, lo)| hi - lo).collect())
+    }
+
+    /// Reduces every lane along `axis` with `reduce`, handling NaN elements according to `policy`.
+    ///
+    /// Only elements whose entry in `mask` is `true` take part. A NaN is any element that does
+    /// not compare with itself. Under `NanPolicy::Propagate` a lane containing NaN yields `nan(x)`
+    /// for its first NaN `x`, under `NanPolicy::Ignore` the NaN elements are skipped (a lane left
+    /// with nothing to reduce still yields `nan(x)`), and under `NanPolicy::Raise` the whole
+    /// computation fails with `ArrayError::NanEncountered`. A lane for which `reduce` returns
+    /// `None` fails with `ArrayError::EmptyArray`.
+    fn reduce_with_nan_policy<R>(
+        &self,
+        axis: Option<usize>,
+        mask: Option<&Array<bool, D>>,
+        policy: NanPolicy,
+        nan: impl Fn(T) -> R,
+        reduce: impl Fn(&mut dyn Iterator<Item = T>) -> Option<R>,
+    ) -> Result<Vec<R>, ArrayError> {
+        self.check_mask(mask)?;
+
+        let is_nan = |x: &T| x.partial_cmp(x).is_none();
+        self.lanes(axis)?
+            .into_iter()
+            .map(|(start, stride, len)| {
+                let lane = || {
+                    (0..len)
+                        .map(move |i| start + i * stride)
+                        .filter(move |&idx| mask.is_none_or(|mask| mask.data[idx]))
+                        .map(|idx| self.data[idx])
+                };
+                let Some(first_nan) = lane().find(is_nan) else {
+                    return reduce(&mut lane()).ok_or(ArrayError::EmptyArray);
+                };
+
+                match policy {
+                    NanPolicy::Propagate => Ok(nan(first_nan)),
+                    NanPolicy::Raise => Err(ArrayError::NanEncountered(format!(
+                        "Lane starting at flat index {} contains NaN",
+                        start
+                    ))),
+                    NanPolicy::Ignore => Ok(reduce(&mut lane().filter(|x| !is_nan(x)))
+                        .unwrap_or_else(|| nan(first_nan))),
+                }
+            })
+            .collect()
+    }
+
+    /// Computes the maximum value(s) like `max_compute`, handling NaN elements according to `policy`.
+    pub fn max_compute_with(
+        &self,
+        axis: Option<usize>,
+        policy: NanPolicy,
+    ) -> Result<Vec<T>, ArrayError> {
+        if self.data.is_empty() {
+            return Err(ArrayError::EmptyArray);
+        }
+        self.max_compute_masked(axis, policy, None, None)
+    }
+
+    /// Computes the maximum value(s) like `max_compute_with`, over the elements selected by
+    /// `mask` and starting from `initial`, as `MaxBuilder` does with `initial` and `where_mask`.
+    pub(crate) fn max_compute_masked(
+        &self,
+        axis: Option<usize>,
+        policy: NanPolicy,
+        initial: Option<T>,
+        mask: Option<&Array<bool, D>>,
+    ) -> Result<Vec<T>, ArrayError> {
+        self.reduce_with_nan_policy(
+            axis,
+            mask,
+            policy,
+            |x| x,
+            |values| {
+                values.fold(initial, |best, x| {
+                    Some(best.map_or(x, |best| if x > best { x } else { best }))
+                })
+            },
+        )
+    }
+
+    /// Computes the minimum value(s) like `min_compute`, handling NaN elements according to `policy`.
+    pub fn min_compute_with(
+        &self,
+        axis: Option<usize>,
+        policy: NanPolicy,
+    ) -> Result<Vec<T>, ArrayError> {
+        if self.data.is_empty() {
+            return Err(ArrayError::EmptyArray);
+        }
+        self.min_compute_masked(axis, policy, None, None)
+    }
+
+    /// Computes the minimum value(s) like `min_compute_with`, over the elements selected by
+    /// `mask` and starting from `initial`, as `MinBuilder` does with `initial` and `where_mask`.
+    pub(crate) fn min_compute_masked(
+        &self,
+        axis: Option<usize>,
+        policy: NanPolicy,
+        initial: Option<T>,
+        mask: Option<&Array<bool, D>>,
+    ) -> Result<Vec<T>, ArrayError> {
+        self.reduce_with_nan_policy(
+            axis,
+            mask,
+            policy,
+            |x| x,
+            |values| {
+                values.fold(initial, |best, x| {
+                    Some(best.map_or(x, |best| if x < best { x } else { best }))
+                })
+            },
+        )
+    }
+
+    /// Computes the mean value(s) like `mean_compute`, handling NaN elements according to `policy`.
+    pub fn mean_compute_with(
+        &self,
+        axis: Option<usize>,
+        policy: NanPolicy,
+    ) -> Result<Vec<f64>, ArrayError>
+    where
+        T: Into<f64>,
+    {
+        if self.data.is_empty() {
+            return Err(ArrayError::EmptyArray);
+        }
+        self.mean_compute_masked(axis, policy, None, None)
+    }
+
+    /// Computes the mean value(s) like `mean_compute_with`, over the elements selected by `mask`.
+    /// A lane with no selected elements yields `initial`, as `MeanBuilder` does with `initial`
+    /// and `where_mask`.
+    pub(crate) fn mean_compute_masked(
+        &self,
+        axis: Option<usize>,
+        policy: NanPolicy,
+        initial: Option<f64>,
+        mask: Option<&Array<bool, D>>,
+    ) -> Result<Vec<f64>, ArrayError>
+    where
+        T: Into<f64>,
+    {
+        self.reduce_with_nan_policy(
+            axis,
+            mask,
+            policy,
+            |_| f64::NAN,
+            |values| {
+                let (sum, count) = values.fold((0.0, 0), |(sum, count), x| {
+                    (sum + Into::<f64>::into(x), count + 1)
+                });
+                if count == 0 {
+                    initial
+                } else {
+                    Some(sum / count as f64)
+                }
+            },
+        )
+    }
+}
+
+impl<T: Copy, D: Dimension> Array<T, D> {
+    /// Reduces the array with `op` along a specified axis or for the whole array, like NumPy's
+    /// `ufunc.reduce` with the `initial` and `where` arguments.
+    ///
+    /// Only elements whose entry in `mask` is `true` take part, and `initial` is folded in first
+    /// when given. A lane with no selected elements yields `initial`, or fails with
+    /// `ArrayError::EmptyArray` if it is unset. Returns `ArrayError::ShapeMismatch` if `mask`
+    /// does not have the shape of the array.
+    pub fn reduce(
+        &self,
+        axis: Option<usize>,
+        initial: Option<T>,
+        mask: Option<&Array<bool, D>>,
+        op: impl Fn(T, T) -> T,
+    ) -> Result<Vec<T>, ArrayError> {
+        self.check_mask(mask)?;
+
+        self.lanes(axis)?
+            .into_iter()
+            .map(|(start, stride, len)| {
+                (0..len)
+                    .map(|i| start + i * stride)
+                    .filter(|&idx| mask.is_none_or(|mask| mask.data[idx]))
+                    .map(|idx| self.data[idx])
+                    .fold(initial, |acc, x| Some(acc.map_or(x, |acc| op(acc, x))))
+                    .ok_or(ArrayError::EmptyArray)
+            })
+            .collect()
+    }
+
+    /// Returns `ArrayError::ShapeMismatch` unless `mask` is unset or has the shape of the array.
+    fn check_mask(&self, mask: Option<&Array<bool, D>>) -> Result<(), ArrayError> {
+        match mask {
+            Some(mask) if mask.shape.dims() != self.shape.dims() => {
+                Err(ArrayError::ShapeMismatch {
+                    lhs: self.shape.dims().to_vec(),
+                    rhs: mask.shape.dims().to_vec(),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<T, D: Dimension> Array<T, D>
+where
+    T: Copy + Zero + Add<Output = T>,
+{
+    /// Computes the sum of the array along a specified axis or for the whole array.
+    ///
+    /// The accumulation happens in the element type, so integer arrays sum to integers.
+    pub fn sum_compute(&self, axis: Option<usize>) -> Result<Vec<T>, ArrayError> {
+        if self.data.is_empty() {
+            return Err(ArrayError::EmptyArray);
+        }
+
+        Ok(self
+            .lanes(axis)?
+            .into_iter()
+            .map(|(start, stride, len)| {
+                (0..len).fold(T::zero(), |acc, i| acc + self.data[start + i * stride])
+            })
+            .collect())
+    }
+
+    /// Computes the cumulative sum along `axis`, or over the flattened array when `axis` is
+    /// `None`. The result has the same shape as the array.
+    ///
+    /// Returns `ArrayError::InvalidAxis` if `axis` is out of bounds.
+    pub fn cumsum(&self, axis: Option<usize>) -> Result<Array<T, D>, ArrayError> {
+        let mut data = self.data.clone();
+        for (start, stride, len) in self.lanes(axis)? {
+            for i in 1..len {
+                let idx = start + i * stride;
+                data[idx] = data[idx - stride] + data[idx];
+            }
+        }
+
+        Ok(Array {
+            data,
+            shape: self.shape.clone(),
+        })
+    }
+}
+
+impl<T, D: Dimension> Array<T, D>
+where
+    T: Copy + One + Mul<Output = T>,
+{
+    /// Computes the product of the array along a specified axis or for the whole array.
+    pub fn prod_compute(&self, axis: Option<usize>) -> Result<Vec<T>, ArrayError> {
+        if self.data.is_empty() {
+            return Err(ArrayError::EmptyArray);
+        }
+
+        Ok(self
+            .lanes(axis)?
+            .into_iter()
+            .map(|(start, stride, len)| {
+                (0..len).fold(T::one(), |acc, i| acc * self.data[start + i * stride])
+            })
+            .collect())
+    }
+
+    /// Computes the cumulative product along `axis`, or over the flattened array when `axis` is
+    /// `None`. The result has the same shape as the array.
+    ///
+    /// Returns `ArrayError::InvalidAxis` if `axis` is out of bounds.
+    pub fn cumprod(&self, axis: Option<usize>) -> Result<Array<T, D>, ArrayError> {
+        let mut data = self.data.clone();
+        for (start, stride, len) in self.lanes(axis)? {
+            for i in 1..len {
+                let idx = start + i * stride;
+                data[idx] = data[idx - stride] * data[idx];
+            }
+        }
+
+        Ok(Array {
+            data,
+            shape: self.shape.clone(),
+        })
+    }
+
+    /// Computes the cumulative product like `cumprod`, checking every multiplication.
+    ///
+    /// Returns `ArrayError::Overflow` naming the flat index of the first product that does not
+    /// fit in `T`, and `ArrayError::InvalidAxis` if `axis` is out of bounds.
+    pub fn checked_cumprod(&self, axis: Option<usize>) -> Result<Array<T, D>, ArrayError>
+    where
+        T: CheckedMul,
+    {
+        let mut data = self.data.clone();
+        for (start, stride, len) in self.lanes(axis)? {
+            for i in 1..len {
+                let idx = start + i * stride;
+                data[idx] = data[idx - stride].checked_mul(&data[idx]).ok_or_else(|| {
+                    ArrayError::Overflow(format!(
+                        "Cumulative product overflows at flat index {}",
+                        idx
+                    ))
+                })?;
+            }
+        }
+
+        Ok(Array {
+            data,
+            shape: self.shape.clone(),
+        })
+    }
+}
+
+impl<T, D: Dimension> Array<T, D>
+where
+    T: Copy + Into<f64>,
+{
+    /// Computes the variance of the array along a specified axis or for the whole array.
+    ///
+    /// The squared deviations from the mean are divided by `N - ddof`, where `N` is the number of
+    /// elements in each lane: `ddof = 0` gives the population variance and `ddof = 1` the sample
+    /// variance. Returns `ArrayError::InvalidArgument` if `ddof` is not smaller than `N`.
+    pub fn var_compute(&self, axis: Option<usize>, ddof: usize) -> Result<Vec<f64>, ArrayError> {
+        if self.data.is_empty() {
+            return Err(ArrayError::EmptyArray);
+        }
+
+        self.lanes(axis)?
+            .into_iter()
+            .map(|(start, stride, len)| {
+                if ddof >= len {
+                    return Err(ArrayError::InvalidArgument(format!(
+                        "Delta degrees of freedom {} must be smaller than the {} elements being reduced",
+                        ddof, len
+                    )));
+                }
+
+                let values = (0..len).map(|i| self.data[start + i * stride].into());
+                let first: f64 = self.data[start].into();
+                if values.clone().all(|x| x == first) {
+                    // Avoid rounding noise from the mean when every element is identical.
+                    return Ok(0.0);
+                }
+
+                let mean = values.clone().sum::<f64>() / len as f64;
+                let squared: f64 = values.map(|x| (x - mean) * (x - mean)).sum();
+                Ok(squared / (len - ddof) as f64)
+            })
+            .collect()
+    }
+
+    /// Returns a sorted copy of every lane along `axis`, converted to `f64`.
+    /// The data of the array itself is left untouched.
+    fn sorted_lanes_f64(&self, axis: Option<usize>) -> Result<Vec<Vec<f64>>, ArrayError> {
+        if self.data.is_empty() {
+            return Err(ArrayError::EmptyArray);
+        }
+
+        Ok(self
+            .lanes(axis)?
+            .into_iter()
+            .map(|(start, stride, len)| {
+                let mut lane: Vec<f64> = (0..len)
+                    .map(|i| self.data[start + i * stride].into())
+                    .collect();
+                lane.sort_by(f64::total_cmp);
+                lane
+            })
+            .collect())
+    }
+
+    /// Computes the median of the array along a specified axis or for the whole array.
+    ///
+    /// For lanes with an even number of elements the two middle values are averaged. A lane
+    /// containing NaN yields NaN, like `max_compute`.
+    pub fn median_compute(&self, axis: Option<usize>) -> Result<Vec<f64>, ArrayError> {
+        Ok(self
+            .sorted_lanes_f64(axis)?
+            .into_iter()
+            .map(|lane| {
+                if lane.iter().any(|x| x.is_nan()) {
+                    return f64::NAN;
+                }
+                let mid = lane.len() / 2;
+                if lane.len() % 2 == 0 {
+                    (lane[mid - 1] + lane[mid]) / 2.0
+                } else {
+                    lane[mid]
+                }
+            })
+            .collect())
+    }
+
+    /// Computes the `q`-th percentile of the array along a specified axis or for the whole array.
+    ///
+    /// Values between the closest ranks are linearly interpolated, matching NumPy's default
+    /// `linear` method. A lane containing NaN yields NaN, like `median_compute`. Returns
+    /// `ArrayError::InvalidArgument` if `q` is not within `[0, 100]`.
+    pub fn percentile_compute(&self, q: f64, axis: Option<usize>) -> Result<Vec<f64>, ArrayError> {
+        if !(0.0..=100.0).contains(&q) {
+            return Err(ArrayError::InvalidArgument(format!(
+                "Percentile {} is not within [0, 100]",
+                q
+            )));
+        }
+
+        Ok(self
+            .sorted_lanes_f64(axis)?
+            .into_iter()
+            .map(|lane| {
+                if lane.iter().any(|x| x.is_nan()) {
+                    return f64::NAN;
+                }
+                let pos = q / 100.0 * (lane.len() - 1) as f64;
+                let lo = pos.floor() as usize;
+                let hi = pos.ceil() as usize;
+                lane[lo] + (lane[hi] - lane[lo]) * (pos - lo as f64)
+            })
+            .collect())
+    }
+
+    /// Computes the standard deviation of the array along a specified axis or for the whole
+    /// array, as the square root of `var_compute` with the same `ddof`.
+    pub fn std_compute(&self, axis: Option<usize>, ddof: usize) -> Result<Vec<f64>, ArrayError> {
+        Ok(self
+            .var_compute(axis, ddof)?
+            .into_iter()
+            .map(|var| if var < 0.0 { 0.0 } else { var }.sqrt())
+            .collect())
+    }
+}
+
+/// Implements an element-wise binary operator for every combination of owned and borrowed
+/// arrays, panicking on mismatched shapes like the builders' `compute()` methods do.
+macro_rules! impl_elementwise_op {
+    ($trait:ident, $method:ident, $try_method:ident, $verb:literal) => {
+        impl<T, D> $trait<&Array<T, D>> for &Array<T, D>
+        where
+            T: Copy + $trait<Output = T>,
+            D: Dimension,
+        {
+            type Output = Array<T, D>;
+
+            fn $method(self, rhs: &Array<T, D>) -> Array<T, D> {
+                match self.$try_method(rhs) {
+                    Ok(result) => result,
+                    Err(err) => panic!("cannot {} arrays: {}", $verb, err),
+                }
+            }
+        }
+
+        impl<T, D> $trait<Array<T, D>> for &Array<T, D>
+        where
+            T: Copy + $trait<Output = T>,
+            D: Dimension,
+        {
+            type Output = Array<T, D>;
+
+            fn $method(self, rhs: Array<T, D>) -> Array<T, D> {
+                self.$method(&rhs)
+            }
+        }
+
+        impl<T, D> $trait<&Array<T, D>> for Array<T, D>
+        where
+            T: Copy + $trait<Output = T>,
+            D: Dimension,
+        {
+            type Output = Array<T, D>;
+
+            fn $method(self, rhs: &Array<T, D>) -> Array<T, D> {
+                (&self).$method(rhs)
+            }
+        }
+
+        impl<T, D> $trait<Array<T, D>> for Array<T, D>
+        where
+            T: Copy + $trait<Output = T>,
+            D: Dimension,
+        {
+            type Output = Array<T, D>;
+
+            fn $method(self, rhs: Array<T, D>) -> Array<T, D> {
+                (&self).$method(&rhs)
+            }
+        }
+    };
+}
+
+impl_elementwise_op!(Add, add, try_add, "add");
+impl_elementwise_op!(Sub, sub, try_sub, "subtract");
+impl_elementwise_op!(Mul, mul, try_mul, "multiply");
+impl_elementwise_op!(Div, div, try_div, "divide");
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{E, PI, TAU};
+
+    use super::PadMode;
+    use crate::operations::{covariance, pearson, NanPolicy, Stat, StreamingReducer};
+    use crate::{
+        arange_f64, arange_i64, broadcast_shapes, concatenate, eye, eye_rect, linspace,
+        linspace_endpoint, stack, test_util, Array, ArrayError, Dimension, Ix, IxDyn, Shape,
+    };
+
+    fn round_to_3dp(value: f64) -> f64 {
+        (value * 1000.0).round() / 1000.0
+    }
+
+    fn assert_vec_approx_eq(actual: Vec<f64>, expected: Vec<f64>) {
+        assert_eq!(actual.len(), expected.len(), "Vectors have different lengths");
+        for (a, e) in actual.iter().zip(expected.iter()) {
+            assert_eq!(round_to_3dp(*a), round_to_3dp(*e), "Values differ: {} != {}", a, e);
+        }
+    }
+
+    #[test]
+    fn assert_array_approx_eq_close_arrays() {
+        let a = arr![[1.0, 2.0], [3.0, f64::NAN]];
+        let b = arr![[1.0 + 1e-10, 2.0], [3.0 - 1e-10, f64::NAN]];
+        assert_array_approx_eq!(a, b);
+        assert_array_approx_eq!(arr![0.1 + 0.2], arr![0.3], 1e-12);
+        assert_array_approx_eq!(a.sqrt().powf(2.0), a, 1e-9);
+    }
+
+    #[test]
+    #[should_panic(expected = "arrays differ in 1 of 3 elements")]
+    fn assert_array_approx_eq_divergent_arrays() {
+        assert_array_approx_eq!(arr![1.0, 2.0, 3.0], arr![1.0, 2.5, 3.0], 1e-3);
+    }
+
+    #[test]
+    #[should_panic(expected = "arrays have different shapes")]
+    fn assert_array_approx_eq_shape_mismatch() {
+        assert_array_approx_eq!(arr![[1.0, 2.0]], arr![[1.0], [2.0]]);
+    }
+
+    #[test]
+    fn array_creation_i64_1d() {
+        let arr = arr![1, 2, 3, 4];
+        let ix = Ix::<1>::new([4]);
+        let shape = Shape::new(ix);
+
+        assert_eq!(arr.shape().raw_dim().size(), 4);
+        assert_eq!(arr.shape().raw_dim().ndim(), 1);
+        assert_eq!(format!("{:?}", arr.shape()), format!("{:?}", shape));
+    }
+
+    #[test]
+    fn array_creation_i64_2d() {
+        let arr = arr![[1, 2], [3, 4], [5, 6]];
+        let ix = Ix::<2>::new([3, 2]);
+        let shape = Shape::new(ix);
+
+        assert_eq!(arr.shape().raw_dim().size(), 6);
+        assert_eq!(arr.shape().raw_dim().ndim(), 2);
+        assert_eq!(format!("{:?}", arr.shape()), format!("{:?}", shape));
+    }
+
+    #[test]
+    fn array_creation_i64_3d() {
+        let arr = arr![[[1, 2, 3], [4, 5, 6]], [[7, 8, 9], [10, 11, 12]]];
+        let ix = Ix::<3>::new([2, 2, 3]);
+        let shape = Shape::new(ix);
+
+        assert_eq!(arr.shape().raw_dim().size(), 12);
+        assert_eq!(arr.shape().raw_dim().ndim(), 3);
+        assert_eq!(format!("{:?}", arr.shape()), format!("{:?}", shape));
+    }
+
+    #[test]
+    fn array_creation_f64_1d() {
+        let arr = arr![1.1, 2.2, 3.3, 4.4];
+        let ix = Ix::<1>::new([4]);
+        let shape = Shape::new(ix);
+
+        assert_eq!(arr.shape().raw_dim().size(), 4);
+        assert_eq!(arr.shape().raw_dim().ndim(), 1);
+        assert_eq!(format!("{:?}", arr.shape()), format!("{:?}", shape));
+    }
+
+    #[test]
+    fn array_creation_f64_2d() {
+        let arr = arr![[1.1, 2.2], [3.3, 4.4], [5.5, 6.6]];
+        let ix = Ix::<2>::new([3, 2]);
+        let shape = Shape::new(ix);
+
+        assert_eq!(arr.shape().raw_dim().size(), 6);
+        assert_eq!(arr.shape().raw_dim().ndim(), 2);
+        assert_eq!(format!("{:?}", arr.shape()), format!("{:?}", shape));
+    }
+
+    #[test]
+    fn array_creation_f64_3d() {
+        let arr = arr![
+            [[1.1, 2.2, 3.3], [4.4, 5.5, 6.6]],
+            [[7.7, 8.8, 9.9], [10.0, 11.1, 12.2]]
+        ];
+        let ix = Ix::<3>::new([2, 2, 3]);
+        let shape = Shape::new(ix);
+
+        assert_eq!(arr.shape().raw_dim().size(), 12);
+        assert_eq!(arr.shape().raw_dim().ndim(), 3);
         assert_eq!(format!("{:?}", arr.shape()), format!("{:?}", shape));
     }
 
     #[test]
-    fn array_creation_i64_3d() {
-        let arr = arr![[[1, 2, 3], [4, 5, 6]], [[7, 8, 9], [10, 11, 12]]];
-        let ix = Ix::<3>::new([2, 2, 3]);
-        let shape = Shape::new(ix);
+    fn array_creation_from_vec_1d() {
+        let arr = arr!(vec![1, 2, 3]);
+        assert_eq!(arr.shape().dims(), &[3]);
+        assert_eq!(arr.data(), &vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn array_creation_from_vec_2d() {
+        let arr = arr!(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+        assert_eq!(arr.shape().dims(), &[2, 3]);
+        assert_eq!(arr.data(), &vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    }
+
+    #[test]
+    fn array_creation_from_vec_3d() {
+        let arr = arr!(vec![
+            vec![vec![1, 2], vec![3, 4], vec![5, 6]],
+            vec![vec![7, 8], vec![9, 10], vec![11, 12]],
+        ]);
+        assert_eq!(arr.shape().dims(), &[2, 3, 2]);
+        assert_eq!(arr.data(), &(1..=12).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn into_vec_2d_row_major() {
+        let arr = arr![[1, 2, 3], [4, 5, 6]];
+        let data: Vec<i64> = arr.into();
+        assert_eq!(data, vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn from_iter_1d_range_and_map() {
+        let arr = Array::from_iter_1d(0..5);
+        assert_eq!(arr.shape().dims(), &[5]);
+        assert_eq!(arr.data(), &vec![0, 1, 2, 3, 4]);
+
+        let arr = Array::from_iter_1d((1..=3).map(|x| x as f64 * 0.5));
+        assert_eq!(arr.data(), &vec![0.5, 1.0, 1.5]);
+
+        let empty = Array::from_iter_1d(std::iter::empty::<i64>());
+        assert_eq!(empty.shape().dims(), &[0]);
+    }
+
+    #[test]
+    fn from_vec_roundtrip() {
+        let arr = Array::from(vec![1.5, 2.5, 3.5]);
+        assert_eq!(arr.shape().dims(), &[3]);
+        assert_eq!(Vec::from(arr), vec![1.5, 2.5, 3.5]);
+    }
+
+    #[test]
+    fn dtype_after_map_i64_to_f64() {
+        let arr = arr![1, 2, 3];
+        assert_eq!(arr.dtype(), "int64");
+
+        let mapped = arr.map(|x| x as f64);
+        assert_eq!(mapped.dtype(), "float64");
+        assert_eq!(mapped.data(), &vec![1.0, 2.0, 3.0]);
+        assert_eq!(format!("{:?}", mapped.shape()), format!("{:?}", arr.shape()));
+    }
+
+    #[test]
+    fn dtype_after_map_f64_to_i64() {
+        let arr = arr![[1.9, -2.5], [3.1, 4.0]];
+        let mapped = arr.map(|x| x as i64);
+        assert_eq!(mapped.dtype(), "int64");
+        assert_eq!(mapped.data(), &vec![1, -2, 3, 4]);
+        assert_eq!(mapped.shape().dims(), &[2, 2]);
+    }
+
+    #[test]
+    fn map_takes_elements_by_value() {
+        let squared = arr![[1, 2, 3], [4, 5, 6]].map(|x| x * x);
+        assert_eq!(squared, arr![[1, 4, 9], [16, 25, 36]]);
+
+        let words = arr![String::from("a"), String::from("bc")];
+        assert_eq!(words.map(|word| word.len()), arr![1, 2]);
+        assert_eq!(words.data()[1], "bc");
+    }
+
+    #[test]
+    fn split_at_mut_axis_2d() {
+        let mut arr = arr![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+        let (top, bottom) = arr.split_at_mut_axis(1, 0).unwrap();
+        assert_eq!(top.len(), 3);
+        assert_eq!(bottom.len(), 6);
+
+        top.iter_mut().for_each(|x| *x *= 10);
+        bottom.iter_mut().for_each(|x| *x = -*x);
+
+        assert_eq!(arr.data(), &vec![10, 20, 30, -4, -5, -6, -7, -8, -9]);
+    }
+
+    #[test]
+    fn split_at_mut_axis_3d_threads() {
+        let mut arr = arr![[[1, 2], [3, 4]], [[5, 6], [7, 8]]];
+        let (first, second) = arr.split_at_mut_axis(1, 0).unwrap();
+
+        std::thread::scope(|s| {
+            s.spawn(|| first.iter_mut().for_each(|x| *x += 100));
+            s.spawn(|| second.iter_mut().for_each(|x| *x *= 2));
+        });
+
+        assert_eq!(arr.data(), &vec![101, 102, 103, 104, 10, 12, 14, 16]);
+    }
+
+    #[test]
+    fn split_at_mut_axis_invalid() {
+        let mut arr = arr![[1, 2], [3, 4]];
+        assert!(matches!(
+            arr.split_at_mut_axis(1, 1),
+            Err(ArrayError::InvalidAxis(_))
+        ));
+        assert!(matches!(
+            arr.split_at_mut_axis(1, 2),
+            Err(ArrayError::InvalidAxis(_))
+        ));
+        assert!(matches!(
+            arr.split_at_mut_axis(3, 0),
+            Err(ArrayError::IndexOutOfBounds(_))
+        ));
+    }
+
+    #[test]
+    fn astype_i64_to_f64() {
+        let arr = arr![[1, -2], [3, 4]];
+        let cast = arr.astype::<f64>();
+        assert_eq!(cast.dtype(), "float64");
+        assert_eq!(cast.data(), &vec![1.0, -2.0, 3.0, 4.0]);
+        assert_eq!(cast.shape().dims(), &[2, 2]);
+    }
+
+    #[test]
+    fn pad_to_shape_2x2_to_3x4() {
+        let arr = arr![[1, 2], [3, 4]];
+        let padded = arr.pad_to_shape(&[3, 4], 0).unwrap();
+        assert_eq!(padded.shape().dims(), &[3, 4]);
+        assert_eq!(padded.data(), &vec![1, 2, 0, 0, 3, 4, 0, 0, 0, 0, 0, 0]);
+
+        assert_eq!(arr.pad_to_shape(&[2, 2], 9).unwrap(), arr);
+    }
+
+    #[test]
+    fn pad_to_shape_errors() {
+        let arr = arr![[1.0, 2.0], [3.0, 4.0]];
+        assert!(matches!(
+            arr.pad_to_shape(&[1, 4], 0.0),
+            Err(ArrayError::DimensionMismatch {
+                expected: 2,
+                actual: 1
+            })
+        ));
+        assert!(matches!(
+            arr.pad_to_shape(&[3, 3, 3], 0.0),
+            Err(ArrayError::DimensionMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn permute_axes_3d() {
+        let arr = arr![[[1, 2, 3], [4, 5, 6]], [[7, 8, 9], [10, 11, 12]]];
+        let permuted = arr.permute_axes([2, 0, 1]).unwrap();
+        assert_eq!(permuted.shape().dims(), &[3, 2, 2]);
+        assert_eq!(
+            permuted.data(),
+            &vec![1, 4, 7, 10, 2, 5, 8, 11, 3, 6, 9, 12]
+        );
+
+        let restored = permuted.permute_axes([1, 2, 0]).unwrap();
+        assert_eq!(restored, arr);
+        assert_eq!(arr.permute_axes([0, 1, 2]).unwrap(), arr);
+    }
+
+    #[test]
+    fn permute_axes_2d_matches_transpose() {
+        let arr = arr![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+        assert_eq!(arr.permute_axes([1, 0]).unwrap(), arr.transpose());
+    }
+
+    #[test]
+    fn permute_axes_rejects_invalid_orders() {
+        let arr = arr![[[1, 2], [3, 4]]];
+        assert!(matches!(
+            arr.permute_axes([0, 0, 1]),
+            Err(ArrayError::InvalidAxis(_))
+        ));
+        assert!(matches!(
+            arr.permute_axes([0, 1, 3]),
+            Err(ArrayError::InvalidAxis(_))
+        ));
+    }
+
+    #[test]
+    fn concatenate_axis_0() {
+        let a = arr![[1, 2, 3], [4, 5, 6]];
+        let b = arr![[7, 8, 9], [10, 11, 12]];
+        let joined = concatenate(&[&a, &b], 0).unwrap();
+        assert_eq!(joined.shape().dims(), &[4, 3]);
+        assert_eq!(joined.data(), &(1..=12).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn concatenate_inner_axes() {
+        let a = arr![[1, 2], [3, 4]];
+        let b = arr![[5], [6]];
+        let joined = concatenate(&[&a, &b, &a], 1).unwrap();
+        assert_eq!(joined.shape().dims(), &[2, 5]);
+        assert_eq!(joined.data(), &vec![1, 2, 5, 1, 2, 3, 4, 6, 3, 4]);
+
+        let c = arr![[[1.0, 2.0]], [[3.0, 4.0]]];
+        let d = arr![[[5.0, 6.0], [7.0, 8.0]], [[9.0, 10.0], [11.0, 12.0]]];
+        let joined = concatenate(&[&c, &d], 1).unwrap();
+        assert_eq!(joined.shape().dims(), &[2, 3, 2]);
+        assert_eq!(
+            joined.data(),
+            &vec![1.0, 2.0, 5.0, 6.0, 7.0, 8.0, 3.0, 4.0, 9.0, 10.0, 11.0, 12.0]
+        );
+    }
+
+    #[test]
+    fn concatenate_errors() {
+        let a = arr![[1, 2], [3, 4]];
+        let b = arr![[5, 6, 7]];
+        assert!(matches!(
+            concatenate(&[&a, &b], 0),
+            Err(ArrayError::DimensionMismatch {
+                expected: 2,
+                actual: 3
+            })
+        ));
+        assert!(matches!(
+            concatenate(&[&a, &a], 2),
+            Err(ArrayError::InvalidAxis(_))
+        ));
+        assert!(matches!(
+            concatenate::<i32, 2>(&[], 0),
+            Err(ArrayError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn stack_three_1d_arrays() {
+        let a = arr![1, 2, 3];
+        let stacked: Array<i32, Ix<2>> = stack(&[&a, &a, &a], 0).unwrap();
+        assert_eq!(stacked.shape().dims(), &[3, 3]);
+        assert_eq!(stacked.data(), &vec![1, 2, 3, 1, 2, 3, 1, 2, 3]);
+
+        let b = arr![4, 5, 6];
+        let stacked: Array<i32, Ix<2>> = stack(&[&a, &b], 1).unwrap();
+        assert_eq!(stacked.shape().dims(), &[3, 2]);
+        assert_eq!(stacked.data(), &vec![1, 4, 2, 5, 3, 6]);
+    }
+
+    #[test]
+    fn stack_2d_into_3d() {
+        let a = arr![[1.0, 2.0], [3.0, 4.0]];
+        let b = arr![[5.0, 6.0], [7.0, 8.0]];
+        let stacked: Array<f64, Ix<3>> = stack(&[&a, &b], 0).unwrap();
+        assert_eq!(stacked.shape().dims(), &[2, 2, 2]);
+        assert_eq!(stacked.data(), &vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+    }
+
+    #[test]
+    fn stack_rejects_mismatched_shapes() {
+        let result: Result<Array<i32, Ix<2>>, _> = stack(&[&arr![1, 2, 3], &arr![1, 2]], 0);
+        assert!(matches!(result, Err(ArrayError::ShapeMismatch { .. })));
+    }
+
+    #[test]
+    fn stack_2d_middle_axis() {
+        let (a, b) = (arr![[1, 2], [3, 4]], arr![[5, 6], [7, 8]]);
+        let stacked: Array<i32, Ix<3>> = stack(&[&a, &b], 1).unwrap();
+        assert_eq!(stacked.shape().dims(), &[2, 2, 2]);
+        assert_eq!(stacked.data(), &vec![1, 2, 5, 6, 3, 4, 7, 8]);
+
+        let stacked: Array<i32, Ix<3>> = stack(&[&a, &b], 2).unwrap();
+        assert_eq!(stacked.data(), &vec![1, 5, 2, 6, 3, 7, 4, 8]);
+    }
+
+    #[test]
+    fn stack_errors() {
+        let a = arr![1, 2];
+        assert!(matches!(
+            stack::<i32, 3, 4>(&[], 0),
+            Err(ArrayError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            stack::<_, 1, 3>(&[&a, &a], 0),
+            Err(ArrayError::DimensionMismatch { .. })
+        ));
+        assert!(matches!(
+            stack::<_, 1, 2>(&[&a, &a], 2),
+            Err(ArrayError::InvalidAxis(_))
+        ));
+    }
+
+    #[test]
+    fn chunks_along_axis_1_uneven() {
+        let arr = arr![[1, 2, 3, 4, 5], [6, 7, 8, 9, 10]];
+        let chunks: Vec<_> = arr.chunks(2, 1).collect();
+
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[0].shape().dims(), &[2, 2]);
+        assert_eq!(chunks[0].data(), &vec![1, 2, 6, 7]);
+        assert_eq!(chunks[1].data(), &vec![3, 4, 8, 9]);
+        assert_eq!(chunks[2].shape().dims(), &[2, 1]);
+        assert_eq!(chunks[2].data(), &vec![5, 10]);
+    }
+
+    #[test]
+    fn chunks_along_axis_0() {
+        let arr = arr![[[1, 2], [3, 4]], [[5, 6], [7, 8]], [[9, 10], [11, 12]]];
+        let chunks: Vec<_> = arr.chunks(2, 0).collect();
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].shape().dims(), &[2, 2, 2]);
+        assert_eq!(chunks[0].data(), &vec![1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(chunks[1].shape().dims(), &[1, 2, 2]);
+        assert_eq!(chunks[1].data(), &vec![9, 10, 11, 12]);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn chunks_zero_size_panics() {
+        let _ = arr![1, 2, 3].chunks(0, 0);
+    }
+
+    #[test]
+    fn split_equal_sections() {
+        let arr = arr![[1, 2, 3, 4], [5, 6, 7, 8]];
+        let parts = arr.split(2, 1).unwrap();
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0], arr![[1, 2], [5, 6]]);
+        assert_eq!(parts[1], arr![[3, 4], [7, 8]]);
+
+        let rows = arr.split(2, 0).unwrap();
+        assert_eq!(rows[1], arr![[5, 6, 7, 8]]);
+    }
+
+    #[test]
+    fn split_errors() {
+        let arr = arr![[1, 2, 3], [4, 5, 6]];
+        assert!(matches!(
+            arr.split(2, 1),
+            Err(ArrayError::DimensionMismatch { .. })
+        ));
+        assert!(matches!(arr.split(1, 2), Err(ArrayError::InvalidAxis(_))));
+        assert!(matches!(
+            arr.split(0, 0),
+            Err(ArrayError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn split_at_train_test_partition() {
+        let arr = arr![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0], [9.0, 10.0]];
+        let parts = arr.split_at(&[4], 0).unwrap();
+        assert_eq!(parts[0].shape().dims(), &[4, 2]);
+        assert_eq!(parts[1], arr![[9.0, 10.0]]);
+
+        let parts = arr.split_at(&[1, 1, 3], 0).unwrap();
+        let lens: Vec<usize> = parts.iter().map(|p| p.shape().dims()[0]).collect();
+        assert_eq!(lens, vec![1, 0, 2, 2]);
+
+        assert!(matches!(
+            arr.split_at(&[3, 2], 0),
+            Err(ArrayError::IndexOutOfBounds(_))
+        ));
+        assert!(matches!(
+            arr.split_at(&[6], 0),
+            Err(ArrayError::IndexOutOfBounds(_))
+        ));
+    }
+
+    #[test]
+    fn ndim_and_shape_vec_match_shape() {
+        let arr = arr![[[1, 2, 3], [4, 5, 6]]];
+        assert_eq!(arr.ndim(), arr.shape().raw_dim().ndim());
+        assert_eq!(arr.ndim(), 3);
+        assert_eq!(arr.shape_vec(), arr.shape().dims().to_vec());
+        assert_eq!(arr.shape_vec(), vec![1, 2, 3]);
+
+        let arr = arr![1.0, 2.0];
+        assert_eq!(arr.ndim(), 1);
+        assert_eq!(arr.shape_vec(), vec![2]);
+    }
+
+    #[test]
+    fn checked_size_detects_overflow() {
+        assert_eq!(Ix::<2>::new([3, 4]).checked_size(), Some(12));
+        assert_eq!(Ix::<2>::new([usize::MAX, 0]).checked_size(), Some(0));
+        assert_eq!(Ix::<2>::new([usize::MAX, 2]).checked_size(), None);
+        assert_eq!(Ix::<3>::new([1 << 32, 1 << 32, 2]).checked_size(), None);
+    }
+
+    #[test]
+    fn constructors_reject_overflowing_shapes() {
+        let shape = Shape::new(Ix::<2>::new([usize::MAX / 2 + 1, 2]));
+        assert!(matches!(
+            Array::new(vec![1, 2], shape),
+            Err(ArrayError::Overflow(_))
+        ));
+
+        assert!(matches!(
+            Array::<u8, Ix<3>>::from_elem(&[usize::MAX, 2, 2], 0),
+            Err(ArrayError::Overflow(_))
+        ));
+
+        assert!(matches!(
+            arr![1, 2, 3].reshape([usize::MAX, 3]),
+            Err(ArrayError::Overflow(_))
+        ));
+    }
+
+    #[test]
+    fn squeeze_drops_length_one_axes() {
+        let arr = arr![[[1], [2], [3]]];
+        assert_eq!(arr.shape().dims(), &[1, 3, 1]);
+
+        let squeezed = arr.squeeze();
+        assert_eq!(squeezed.shape().dims(), &[3]);
+        assert_eq!(squeezed.ndim(), 1);
+        assert_eq!(squeezed.data(), arr.data());
+        assert_eq!(squeezed.sum().compute(), vec![6]);
+    }
+
+    #[test]
+    fn expand_dims_inserts_axis() {
+        let arr = arr![1, 2, 3];
+        let row: Array<i32, Ix<2>> = arr.expand_dims(0).unwrap();
+        assert_eq!(row.shape().dims(), &[1, 3]);
+        assert_eq!(row.data(), arr.data());
+
+        let column = arr.expand_dims::<2>(1).unwrap();
+        assert_eq!(column.shape().dims(), &[3, 1]);
+
+        let arr = arr![[1.0, 2.0], [3.0, 4.0]];
+        let middle = arr.expand_dims::<3>(1).unwrap();
+        assert_eq!(middle.shape().dims(), &[2, 1, 2]);
+        assert_eq!(middle.squeeze().shape().dims(), arr.shape().dims());
+    }
+
+    #[test]
+    fn expand_dims_errors() {
+        let arr = arr![1, 2, 3];
+        assert!(matches!(
+            arr.expand_dims::<2>(2),
+            Err(ArrayError::InvalidAxis(_))
+        ));
+        assert!(matches!(
+            arr.expand_dims::<3>(0),
+            Err(ArrayError::DimensionMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn squeeze_keeps_single_axis() {
+        let arr = arr![[[4.5]]];
+        let squeezed: Array<f64, IxDyn> = arr.squeeze();
+        assert_eq!(squeezed.shape().dims(), &[1]);
+
+        let arr = arr![[1, 2], [3, 4]];
+        assert_eq!(arr.squeeze().shape().dims(), &[2, 2]);
+    }
+
+    #[test]
+    fn ixdyn_behaves_the_same_inline_and_on_heap() {
+        for rank in 0..=8 {
+            let dims: Vec<usize> = (1..=rank).collect();
+            let ix = IxDyn::new(&dims);
+            assert_eq!(ix.dims(), &dims[..]);
+            assert_eq!(ix.ndim(), rank);
+            assert_eq!(ix.size(), dims.iter().product::<usize>());
+            assert_eq!(ix.clone(), ix);
+            assert_eq!(format!("{:?}", ix), format!("IxDyn {{ dims: {:?} }}", dims));
+        }
+
+        assert_ne!(IxDyn::new(&[2, 3]), IxDyn::new(&[2, 3, 1]));
+        assert_ne!(IxDyn::new(&[1, 2, 3, 4]), IxDyn::new(&[1, 2, 3, 4, 5]));
+
+        let data: Vec<i32> = (0..32).collect();
+        let high = Array::new(data.clone(), Shape::new(IxDyn::new(&[2; 5]))).unwrap();
+        assert_eq!(high.shape().dims(), &[2; 5]);
+        assert_eq!(high.sum().axis(4).compute().len(), 16);
+        let low = Array::new(data, Shape::new(IxDyn::new(&[4, 8]))).unwrap();
+        assert_eq!(low.sum().compute(), high.sum().compute());
+    }
+
+    #[test]
+    fn flatten_2d() {
+        let arr = arr![[1, 2, 3], [4, 5, 6]];
+        let flat = arr.flatten();
+        assert_eq!(flat.shape().dims(), &[6]);
+        assert_eq!(flat.data(), arr.data());
+        assert_eq!(flat.max().compute(), vec![6]);
+    }
+
+    #[test]
+    fn flatten_3d() {
+        let arr = arr![[[1.0, 2.0], [3.0, 4.0]], [[5.0, 6.0], [7.0, 8.0]]];
+        let flat = arr.ravel();
+        assert_eq!(flat.shape().dims(), &[arr.shape().size()]);
+        assert_eq!(flat.data(), arr.data());
+        assert_eq!(flat, arr.flatten());
+        assert_eq!(flat.mean().compute(), vec![4.5]);
+    }
+
+    #[test]
+    fn reshape_1d_to_2d() {
+        let arr = arr![1, 2, 3, 4, 5, 6];
+        let reshaped = arr.reshape([2, 3]).unwrap();
+        assert_eq!(reshaped.shape().dims(), &[2, 3]);
+        assert_eq!(reshaped.data(), &vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(reshaped.max().axis(1).compute(), vec![3, 6]);
+
+        let back = reshaped.reshape([6]).unwrap();
+        assert_eq!(back.data(), arr.data());
+    }
+
+    #[test]
+    fn reshape_3d_roundtrip() {
+        let arr = arr![[[1.0, 2.0], [3.0, 4.0]], [[5.0, 6.0], [7.0, 8.0]]];
+        let flat = arr.reshape([4, 2]).unwrap();
+        assert_eq!(flat.shape().dims(), &[4, 2]);
+        assert_eq!(flat.data(), arr.data());
+    }
+
+    #[test]
+    fn reshape_size_mismatch() {
+        let arr = arr![1, 2, 3, 4, 5, 6];
+        assert!(matches!(
+            arr.reshape([4, 2]),
+            Err(ArrayError::DimensionMismatch {
+                expected: 8,
+                actual: 6
+            })
+        ));
+    }
+
+    #[test]
+    fn reshape_then_reduce_pipeline() {
+        let result = arr![1, 2, 3, 4, 5, 6]
+            .astype::<f64>()
+            .reshape([2, 3])
+            .unwrap()
+            .mean()
+            .axis(0)
+            .compute();
+        assert_vec_approx_eq(result, vec![2.5, 3.5, 4.5]);
+    }
+
+    /// A heap-backed integer that is `Clone` but not `Copy`, standing in for big-integer types.
+    #[derive(Debug, Clone, PartialEq)]
+    struct BoxedInt(Box<i64>);
+
+    impl std::ops::Add for BoxedInt {
+        type Output = BoxedInt;
+
+        fn add(self, rhs: BoxedInt) -> BoxedInt {
+            BoxedInt(Box::new(*self.0 + *rhs.0))
+        }
+    }
+
+    impl std::ops::Mul for BoxedInt {
+        type Output = BoxedInt;
+
+        fn mul(self, rhs: BoxedInt) -> BoxedInt {
+            BoxedInt(Box::new(*self.0 * *rhs.0))
+        }
+    }
+
+    impl num_traits::Zero for BoxedInt {
+        fn zero() -> Self {
+            BoxedInt(Box::new(0))
+        }
+
+        fn is_zero(&self) -> bool {
+            *self.0 == 0
+        }
+    }
+
+    impl num_traits::One for BoxedInt {
+        fn one() -> Self {
+            BoxedInt(Box::new(1))
+        }
+    }
+
+    #[test]
+    fn fill_zeros_non_copy() {
+        let data = (1..=6).map(|x| BoxedInt(Box::new(x))).collect();
+        let mut arr = Array::new(data, Shape::new(Ix::<2>::new([2, 3]))).unwrap();
+
+        arr.fill_zeros();
+
+        assert_eq!(arr.shape().dims(), &[2, 3]);
+        assert_eq!(arr.data(), &vec![BoxedInt(Box::new(0)); 6]);
+    }
+
+    #[test]
+    fn fill_ones_non_copy() {
+        let data = (1..=8).map(|x| BoxedInt(Box::new(x))).collect();
+        let mut arr = Array::new(data, Shape::new(Ix::<3>::new([2, 2, 2]))).unwrap();
+
+        arr.fill_ones();
+
+        assert_eq!(arr.shape().dims(), &[2, 2, 2]);
+        assert_eq!(arr.data(), &vec![BoxedInt(Box::new(1)); 8]);
+    }
+
+    #[test]
+    fn fill_zeros_and_ones_copy() {
+        let mut arr = arr![[1.5, 2.5], [3.5, 4.5]];
+        arr.fill_zeros();
+        assert_eq!(arr.data(), &vec![0.0; 4]);
+        arr.fill_ones();
+        assert_eq!(arr.data(), &vec![1.0; 4]);
+    }
+
+    #[test]
+    fn cumsum_f64_avoids_i64_overflow() {
+        let arr = arr![i64::MAX, i64::MAX, 1i64];
+        assert!(arr.data()[0].checked_add(arr.data()[1]).is_none());
+
+        let result = arr.cumsum_f64(None).unwrap();
+        assert_eq!(result.shape().dims(), &[3]);
+        assert!(result.data().iter().all(|x| x.is_finite()));
+        assert_eq!(result.data()[0], i64::MAX as f64);
+        assert_eq!(result.data()[1], 2.0 * i64::MAX as f64);
+    }
+
+    #[test]
+    fn cumsum_f64_axes() {
+        let arr = arr![[1, 2, 3], [4, 5, 6]];
+        assert_eq!(
+            arr.cumsum_f64(None).unwrap().data(),
+            &vec![1.0, 3.0, 6.0, 10.0, 15.0, 21.0]
+        );
+        assert_eq!(
+            arr.cumsum_f64(Some(0)).unwrap().data(),
+            &vec![1.0, 2.0, 3.0, 5.0, 7.0, 9.0]
+        );
+        assert_eq!(
+            arr.cumsum_f64(Some(1)).unwrap().data(),
+            &vec![1.0, 3.0, 6.0, 4.0, 9.0, 15.0]
+        );
+        assert_eq!(arr.cumsum_f64(Some(1)).unwrap().shape().dims(), &[2, 3]);
+        assert!(matches!(
+            arr.cumsum_f64(Some(2)),
+            Err(ArrayError::InvalidAxis(_))
+        ));
+    }
+
+    #[test]
+    fn cumsum_1d() {
+        let arr = arr![1, 2, 3, 4];
+        let result = arr.cumsum(None).unwrap();
+        assert_eq!(result.shape().dims(), &[4]);
+        assert_eq!(result.data(), &vec![1, 3, 6, 10]);
+    }
+
+    #[test]
+    fn cumsum_axes() {
+        let arr = arr![[1, 2, 3], [4, 5, 6]];
+        assert_eq!(arr.cumsum(None).unwrap().data(), &vec![1, 3, 6, 10, 15, 21]);
+        assert_eq!(arr.cumsum(Some(0)).unwrap().data(), &vec![1, 2, 3, 5, 7, 9]);
+        assert_eq!(arr.cumsum(Some(1)).unwrap().data(), &vec![1, 3, 6, 4, 9, 15]);
+
+        let arr = arr![[[1.0, 2.0], [3.0, 4.0]], [[5.0, 6.0], [7.0, 8.0]]];
+        assert_eq!(
+            arr.cumsum(Some(2)).unwrap().data(),
+            &vec![1.0, 3.0, 3.0, 7.0, 5.0, 11.0, 7.0, 15.0]
+        );
+        assert_eq!(
+            arr.cumsum(Some(0)).unwrap().data(),
+            &vec![1.0, 2.0, 3.0, 4.0, 6.0, 8.0, 10.0, 12.0]
+        );
+        assert!(matches!(arr.cumsum(Some(3)), Err(ArrayError::InvalidAxis(_))));
+    }
+
+    #[test]
+    fn cumprod_factorials() {
+        let arr = arr![1i64, 2, 3, 4, 5];
+        assert_eq!(arr.cumprod(None).unwrap().data(), &vec![1, 2, 6, 24, 120]);
+    }
+
+    #[test]
+    fn cumprod_axes() {
+        let arr = arr![[1, 2, 3], [4, 5, 6]];
+        assert_eq!(arr.cumprod(Some(0)).unwrap().data(), &vec![1, 2, 3, 4, 10, 18]);
+        assert_eq!(arr.cumprod(Some(1)).unwrap().data(), &vec![1, 2, 6, 4, 20, 120]);
+
+        let arr = arr![[[1, 2], [3, 4]], [[5, 6], [7, 8]]];
+        assert_eq!(
+            arr.cumprod(Some(1)).unwrap().data(),
+            &vec![1, 2, 3, 8, 5, 6, 35, 48]
+        );
+        assert!(matches!(arr.cumprod(Some(3)), Err(ArrayError::InvalidAxis(_))));
+    }
+
+    #[test]
+    fn checked_cumprod_matches_cumprod() {
+        let arr = arr![[1i64, 2, 3], [4, 5, 6]];
+        assert_eq!(
+            arr.checked_cumprod(Some(1)).unwrap(),
+            arr.cumprod(Some(1)).unwrap()
+        );
+        assert!(matches!(
+            arr.checked_cumprod(Some(2)),
+            Err(ArrayError::InvalidAxis(_))
+        ));
+    }
+
+    #[test]
+    fn checked_cumprod_overflow_position() {
+        let arr: Array<i64, Ix<1>> = Array::from(vec![2; 64]);
+        let err = arr.checked_cumprod(None).unwrap_err();
+        assert!(matches!(err, ArrayError::Overflow(_)));
+        assert!(err.to_string().starts_with("Overflow: Cumulative product"));
+        assert!(err.to_string().contains("flat index 62"));
+
+        let arr: Array<i64, Ix<1>> = Array::from(vec![2; 62]);
+        assert_eq!(arr.checked_cumprod(None).unwrap().data()[61], 1 << 62);
+
+        let columns = Array::from(vec![2u8; 16]).reshape([8, 2]).unwrap();
+        let err = columns.checked_cumprod(Some(0)).unwrap_err();
+        assert!(err.to_string().contains("flat index 14"));
+    }
+
+    #[test]
+    fn cummean_1d() {
+        let arr = arr![2, 4, 6];
+        let result = arr.cummean(None).unwrap();
+        assert_eq!(result.shape().dims(), &[3]);
+        assert_vec_approx_eq(result.data().clone(), vec![2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn cummean_2d_axes() {
+        let arr = arr![[1.0, 3.0], [5.0, 7.0]];
+        assert_vec_approx_eq(
+            arr.cummean(Some(0)).unwrap().data().clone(),
+            vec![1.0, 3.0, 3.0, 5.0],
+        );
+        assert_vec_approx_eq(
+            arr.cummean(Some(1)).unwrap().data().clone(),
+            vec![1.0, 2.0, 5.0, 6.0],
+        );
+        assert_vec_approx_eq(
+            arr.cummean(None).unwrap().data().clone(),
+            vec![1.0, 2.0, 3.0, 4.0],
+        );
+        assert!(matches!(
+            arr.cummean(Some(2)),
+            Err(ArrayError::InvalidAxis(_))
+        ));
+    }
+
+    #[test]
+    fn cumvar_1d() {
+        let arr = arr![2, 4, 6, 8];
+        let result = arr.cumvar(None).unwrap();
+        assert_eq!(result.shape().dims(), &[4]);
+        assert_vec_approx_eq(result.data().clone(), vec![0.0, 1.0, 2.667, 5.0]);
+    }
+
+    #[test]
+    fn cumvar_final_entry_matches_var() {
+        let arr = arr![[1.5, -2.0, 3.25], [4.0, 0.5, -1.75]];
+
+        let rows = arr.cumvar(Some(1)).unwrap();
+        let expected = arr.var().axis(1).compute();
+        assert!((rows.data()[2] - expected[0]).abs() < 1e-12);
+        assert!((rows.data()[5] - expected[1]).abs() < 1e-12);
+
+        let cols = arr.cumvar(Some(0)).unwrap();
+        assert_vec_approx_eq(cols.data()[3..].to_vec(), arr.var().axis(0).compute());
+
+        let flat = arr.cumvar(None).unwrap();
+        assert!((flat.data()[5] - arr.var().compute()[0]).abs() < 1e-12);
+    }
+
+    #[test]
+    fn cumvar_invalid_axis() {
+        let arr = arr![[1.0, 2.0], [3.0, 4.0]];
+        assert!(matches!(
+            arr.cumvar(Some(2)),
+            Err(ArrayError::InvalidAxis(_))
+        ));
+    }
+
+    #[test]
+    fn cumvar_is_stable_for_large_offsets() {
+        let arr = arr![1e9 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0];
+        assert!((arr.cumvar(None).unwrap().data()[3] - 22.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn sum_of_squares_and_rms_1d() {
+        let arr = arr![3.0, 4.0];
+        assert_eq!(arr.sum_of_squares(None).unwrap(), vec![25.0]);
+        assert_vec_approx_eq(arr.rms(None).unwrap(), vec![3.536]);
+    }
+
+    #[test]
+    fn sum_of_squares_and_rms_2d() {
+        let arr = arr![[1, 2], [3, 4]];
+        assert_eq!(arr.sum_of_squares(None).unwrap(), vec![30.0]);
+        assert_eq!(arr.sum_of_squares(Some(0)).unwrap(), vec![10.0, 20.0]);
+        assert_eq!(arr.sum_of_squares(Some(1)).unwrap(), vec![5.0, 25.0]);
+        assert_vec_approx_eq(arr.rms(Some(1)).unwrap(), vec![1.581, 3.536]);
+    }
+
+    #[test]
+    fn sum_of_squares_and_rms_errors() {
+        let arr = arr![[1.0, 2.0], [3.0, 4.0]];
+        assert!(matches!(
+            arr.sum_of_squares(Some(2)),
+            Err(ArrayError::InvalidAxis(_))
+        ));
+        assert!(matches!(arr.rms(Some(2)), Err(ArrayError::InvalidAxis(_))));
+
+        let empty: Array<f64, Ix<2>> =
+            Array::new(vec![], Shape::new(Ix::<2>::new([2, 0]))).unwrap();
+        assert!(matches!(empty.rms(Some(1)), Err(ArrayError::EmptyArray)));
+        assert!(matches!(
+            empty.sum_of_squares(None),
+            Err(ArrayError::EmptyArray)
+        ));
+    }
+
+    #[test]
+    fn mean_f32_matches_f64() {
+        let arr: Array<f32, Ix<2>> = arr![[0.1, 0.2, 0.3], [1.5, -2.25, 4.0]];
+        let wide = arr.astype::<f64>();
+
+        for axis in [None, Some(0), Some(1)] {
+            let narrow = arr.mean_f32(axis).unwrap();
+            let reference = wide.mean_compute(axis).unwrap();
+            assert_eq!(narrow.len(), reference.len());
+            for (a, b) in narrow.iter().zip(&reference) {
+                assert!((*a as f64 - b).abs() < 1e-6, "{} != {}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn mean_f32_errors() {
+        let arr: Array<f32, Ix<1>> = arr![1.0, 2.0];
+        assert!(matches!(arr.mean_f32(Some(1)), Err(ArrayError::InvalidAxis(_))));
+
+        let empty: Array<f32, Ix<1>> = Array::new(vec![], Shape::new(Ix::<1>::new([0]))).unwrap();
+        assert!(matches!(empty.mean_f32(None), Err(ArrayError::EmptyArray)));
+    }
+
+    #[test]
+    fn all_and_any_whole_array() {
+        let mask = arr![1, -2, 3].map(|x| x > 0);
+        assert_eq!(mask.all(None).unwrap(), vec![false]);
+        assert_eq!(mask.any(None).unwrap(), vec![true]);
+
+        let mask = arr![2.0, 4.0].map(|x| x > 1.0);
+        assert_eq!(mask.all(None).unwrap(), vec![true]);
+    }
+
+    #[test]
+    fn all_and_any_axes() {
+        let mask = arr![[1, 0, 1], [1, 1, 0]].map(|x| x == 1);
+        assert_eq!(mask.all(Some(0)).unwrap(), vec![true, false, false]);
+        assert_eq!(mask.any(Some(0)).unwrap(), vec![true, true, true]);
+        assert_eq!(mask.all(Some(1)).unwrap(), vec![false, false]);
+        assert_eq!(mask.any(Some(1)).unwrap(), vec![true, true]);
+        assert!(matches!(mask.all(Some(2)), Err(ArrayError::InvalidAxis(_))));
+    }
+
+    #[test]
+    fn all_and_any_empty() {
+        let empty: Array<bool, Ix<1>> = Array::new(vec![], Shape::new(Ix::<1>::new([0]))).unwrap();
+        assert!(matches!(empty.all(None), Err(ArrayError::EmptyArray)));
+        assert!(matches!(empty.any(None), Err(ArrayError::EmptyArray)));
+    }
+
+    #[test]
+    fn trigonometric_functions() {
+        assert_vec_approx_eq(arr![0.0, PI / 2.0].sin().data().clone(), vec![0.0, 1.0]);
+        assert_vec_approx_eq(arr![0.0, PI].cos().data().clone(), vec![1.0, -1.0]);
+        assert_vec_approx_eq(arr![0.0, PI / 4.0].tan().data().clone(), vec![0.0, 1.0]);
+    }
+
+    #[test]
+    fn exponential_and_logarithmic_functions() {
+        let arr = arr![[1.0, 4.0], [100.0, 0.25]];
+        let roots = arr.sqrt();
+        assert_eq!(roots.shape().dims(), &[2, 2]);
+        assert_vec_approx_eq(roots.data().clone(), vec![1.0, 2.0, 10.0, 0.5]);
+
+        assert_vec_approx_eq(arr![0.0, 1.0].exp().data().clone(), vec![1.0, E]);
+        assert_vec_approx_eq(arr![1.0, E * E].ln().data().clone(), vec![0.0, 2.0]);
+        assert_vec_approx_eq(arr![1.0, 1000.0].log10().data().clone(), vec![0.0, 3.0]);
+
+        assert!(arr![-1.0].sqrt().data()[0].is_nan());
+    }
+
+    #[test]
+    fn count_nan_2d() {
+        let nan = f64::NAN;
+        let arr = arr![[1.0, nan, 3.0], [nan, nan, nan], [7.0, 8.0, 9.0]];
+        assert_eq!(arr.count_nan(None).unwrap(), vec![4]);
+        assert_eq!(arr.count_nan(Some(0)).unwrap(), vec![1, 2, 1]);
+        assert_eq!(arr.count_nan(Some(1)).unwrap(), vec![1, 3, 0]);
+
+        assert_eq!(arr![1.0, 2.0].count_nan(None).unwrap(), vec![0]);
+        assert!(matches!(
+            arr.count_nan(Some(2)),
+            Err(ArrayError::InvalidAxis(_))
+        ));
+    }
+
+    #[test]
+    fn all_close_to_ones() {
+        let mut arr = arr![[0.0, 0.0], [0.0, 0.0]];
+        arr.ones();
+        assert!(arr.all_close_to(1.0, 0.0));
+        assert!(!arr.all_close_to(0.0, 0.5));
+
+        let perturbed = arr.add_scalar(1e-9);
+        assert!(perturbed.all_close_to(1.0, 1e-6));
+        assert!(!perturbed.all_close_to(1.0, 1e-12));
+
+        assert!(!arr![1.0, f64::NAN].all_close_to(1.0, 1.0));
+    }
+
+    #[test]
+    fn histogram_counts_and_edges() {
+        let arr = arr![0.0, 1.0, 1.5, 2.0, 4.0];
+        let (counts, edges) = arr.histogram(4).unwrap();
+        assert_eq!(counts, vec![1, 2, 1, 1]);
+        assert_vec_approx_eq(edges, vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+
+        let (counts, edges) = arr![3.0, 3.0].histogram(2).unwrap();
+        assert_eq!(counts, vec![0, 2]);
+        assert_vec_approx_eq(edges, vec![2.5, 3.0, 3.5]);
+    }
+
+    #[test]
+    fn mode_binned_clustered_sample() {
+        let arr = arr![1.02, 0.98, 5.1, 4.95, 5.05, 5.0, 4.9, 9.7, 1.1, 5.02];
+        let mode = arr.mode_binned(10).unwrap();
+        assert!((mode - 5.0).abs() < 0.5, "mode {} is not near 5", mode);
+
+        let arr = arr![[0.1, 0.2], [0.15, 3.0]];
+        assert!((arr.mode_binned(3).unwrap() - (0.1 + 2.9 / 6.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn histogram_errors() {
+        let arr = arr![1.0, f64::NAN];
+        assert!(matches!(arr.mode_binned(4), Err(ArrayError::NanEncountered(_))));
+        assert!(matches!(
+            arr![1.0, 2.0].histogram(0),
+            Err(ArrayError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn compute_array_keeps_remaining_axes() {
+        let arr = arr![[[1, 2, 3], [4, 5, 6]], [[7, 8, 9], [10, 11, 12]]];
+
+        let summed = arr.sum().axis(1).compute_array();
+        assert_eq!(summed.shape().dims(), &[2, 3]);
+        assert_eq!(summed.data(), &vec![5, 7, 9, 17, 19, 21]);
+
+        let maxed = arr.max().axis(2).compute_array();
+        assert_eq!(maxed.shape().dims(), &[2, 2]);
+        assert_eq!(maxed.data(), &vec![3, 6, 9, 12]);
+
+        let chained = maxed.max().axis(0).compute_array();
+        assert_eq!(chained.shape().dims(), &[2]);
+        assert_eq!(chained.data(), &vec![9, 12]);
+
+        assert_eq!(arr.argmin().axis(0).compute_array().shape().dims(), &[2, 3]);
+        assert_eq!(arr.ptp().axis(0).compute_array().shape().dims(), &[2, 3]);
+    }
+
+    #[test]
+    fn compute_array_float_reductions() {
+        let arr = arr![[1.0, 2.0, 4.0], [3.0, 6.0, 8.0]];
+
+        let mean = arr.mean().axis(0).compute_array();
+        assert_eq!(mean.shape().dims(), &[3]);
+        assert_eq!(mean.data(), &vec![2.0, 4.0, 6.0]);
+
+        assert_eq!(arr.var().axis(1).compute_array().shape().dims(), &[2]);
+        assert_eq!(arr.median().compute_array().shape().dims(), &[1]);
+        assert_eq!(arr.percentile(50.0).axis(1).compute_array().shape().dims(), &[2]);
+        assert_eq!(arr![1, 2, 3].prod().axis(0).compute_array().shape().dims(), &[1]);
+    }
+
+    #[test]
+    fn free_functions_match_methods() {
+        let arr = arr![[1.5, -2.0, 3.0], [4.0, 0.5, -6.0]];
+
+        assert_eq!(crate::sum(&arr, None), arr.sum().compute());
+        assert_eq!(crate::sum(&arr, Some(0)), arr.sum().axis(0).compute());
+        assert_eq!(crate::mean(&arr, Some(1)), arr.mean().axis(1).compute());
+        assert_eq!(crate::max(&arr, Some(0)), arr.max().axis(0).compute());
+        assert_eq!(crate::min(&arr, None), arr.min().compute());
+
+        let ints = arr![[[1, 2], [3, 4]], [[5, 6], [7, 8]]];
+        assert_eq!(crate::sum(&ints, Some(2)), vec![3, 7, 11, 15]);
+        assert_eq!(crate::max(&ints, Some(1)), ints.max().axis(1).compute());
+        assert_eq!(crate::mean(&ints, None), vec![4.5]);
+    }
+
+    #[test]
+    fn covariance_hand_computed() {
+        let a = arr![1.0, 2.0, 3.0, 4.0];
+        let b = arr![2.0, 4.0, 5.0, 9.0];
+        assert!((covariance(&a, &b, 0).unwrap() - 2.75).abs() < 1e-12);
+        assert!((covariance(&a, &b, 1).unwrap() - 11.0 / 3.0).abs() < 1e-12);
+        assert!((covariance(&a, &a, 0).unwrap() - a.var().compute()[0]).abs() < 1e-12);
+    }
+
+    #[test]
+    fn covariance_errors() {
+        let a = arr![1.0, 2.0, 3.0];
+        assert!(matches!(
+            covariance(&a, &arr![1.0, 2.0], 0),
+            Err(ArrayError::DimensionMismatch { .. })
+        ));
+        assert!(matches!(
+            covariance(&a, &a, 3),
+            Err(ArrayError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn pearson_correlated_pairs() {
+        let a = arr![1.0, 2.0, 3.0, 4.0, 5.0];
+        assert!((pearson(&a, &arr![3.0, 5.0, 7.0, 9.0, 11.0]).unwrap() - 1.0).abs() < 1e-12);
+        assert!((pearson(&a, &arr![10.0, 8.0, 6.0, 4.0, 2.0]).unwrap() + 1.0).abs() < 1e-12);
+        assert!(pearson(&a, &arr![2.0, 1.0, 0.0, 1.0, 2.0]).unwrap().abs() < 1e-12);
+    }
+
+    #[test]
+    fn pearson_rejects_constant_series() {
+        let a = arr![1.0, 2.0, 3.0];
+        assert!(matches!(
+            pearson(&a, &arr![4.0, 4.0, 4.0]),
+            Err(ArrayError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            pearson(&arr![0.1, 0.1, 0.1], &arr![1.0, 2.0, 3.0]),
+            Err(ArrayError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            pearson(&arr![1.0, 2.0, 3.0], &arr![1e-7, 1e-7, 1e-7]),
+            Err(ArrayError::InvalidArgument(_))
+        ));
+        let nearly = arr![1e6, 1e6 + 1e-3, 1e6 + 2e-3];
+        assert!((pearson(&a, &nearly).unwrap() - 1.0).abs() < 1e-6);
+        assert!(matches!(
+            pearson(&a, &arr![1.0]),
+            Err(ArrayError::DimensionMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn entropy_uniform_base_2() {
+        let arr = arr![0.25, 0.25, 0.25, 0.25];
+        assert_vec_approx_eq(arr.entropy(None, 2.0).unwrap(), vec![2.0]);
+    }
+
+    #[test]
+    fn entropy_axis_skips_zeros() {
+        let arr = arr![[0.5, 0.5, 0.0], [1.0, 0.0, 0.0]];
+        assert_vec_approx_eq(arr.entropy(Some(1), 2.0).unwrap(), vec![1.0, 0.0]);
+        assert_vec_approx_eq(
+            arr.entropy(Some(1), std::f64::consts::E).unwrap(),
+            vec![std::f64::consts::LN_2, 0.0],
+        );
+    }
+
+    #[test]
+    fn entropy_rejects_negative_probabilities() {
+        let arr = arr![0.5, -0.5, 1.0];
+        assert!(matches!(
+            arr.entropy(None, 2.0),
+            Err(ArrayError::DataTypeMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn softmax_hand_computed() {
+        let arr = arr![1.0, 2.0, 3.0];
+        let result = arr.softmax(0).unwrap();
+        assert_vec_approx_eq(result.data().clone(), vec![0.090, 0.245, 0.665]);
+    }
+
+    #[test]
+    fn softmax_lanes_sum_to_one() {
+        let arr = arr![[1, 2, 3], [1000, 1000, 1000]];
+
+        let rows = arr.softmax(1).unwrap();
+        assert_eq!(rows.shape().dims(), &[2, 3]);
+        let row_sums = rows.data().chunks(3).map(|row| row.iter().sum()).collect();
+        assert_vec_approx_eq(row_sums, vec![1.0, 1.0]);
+        assert_vec_approx_eq(rows.data()[3..].to_vec(), vec![1.0 / 3.0; 3]);
+
+        let cols = arr.softmax(0).unwrap();
+        let col_sums = (0..3).map(|c| cols.data()[c] + cols.data()[c + 3]).collect();
+        assert_vec_approx_eq(col_sums, vec![1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn softmax_invalid_axis() {
+        let arr = arr![1.0, 2.0];
+        assert!(matches!(arr.softmax(1), Err(ArrayError::InvalidAxis(_))));
+    }
+
+    #[test]
+    fn reduce_sum_positive_with_mask() {
+        let arr = arr![[1, -2, 3], [-4, 5, -6]];
+        let positive = arr.map(|x| x > 0);
+
+        let total = arr.sum().where_mask(&positive).initial(0).compute();
+        assert_eq!(total, vec![9]);
+
+        let rows = arr.sum().axis(1).where_mask(&positive).initial(0).compute();
+        assert_eq!(rows, vec![4, 5]);
+
+        let cols = arr.sum().axis(0).where_mask(&positive).initial(0).compute();
+        assert_eq!(cols, vec![1, 5, 3]);
+    }
+
+    #[test]
+    fn reduce_initial_is_folded_in() {
+        let arr = arr![1, 2, 3];
+        assert_eq!(arr.sum().initial(10).compute(), vec![16]);
+        assert_eq!(arr.prod().initial(2).compute(), vec![12]);
+        assert_eq!(arr.reduce(None, None, None, i32::max).unwrap(), vec![3]);
+    }
+
+    #[test]
+    fn reduce_empty_after_mask() {
+        let arr = arr![[-1, -2], [3, 4]];
+        let positive = arr.map(|x| x > 0);
+
+        assert_eq!(
+            arr.sum().axis(1).where_mask(&positive).initial(0).compute(),
+            vec![0, 7]
+        );
+        assert!(matches!(
+            arr.sum().axis(1).where_mask(&positive).try_compute(),
+            Err(ArrayError::EmptyArray)
+        ));
+
+        let other_shape = arr![[true, false]];
+        assert!(matches!(
+            arr.reduce(None, Some(0), Some(&other_shape), |a, b| a + b),
+            Err(ArrayError::ShapeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn max_min_mean_with_mask_and_initial() {
+        let arr = arr![[1.0, -2.0, 3.0], [-4.0, 5.0, -6.0]];
+        let negative = arr.map(|x| x < 0.0);
+
+        assert_eq!(arr.max().where_mask(&negative).compute(), vec![-2.0]);
+        assert_eq!(
+            arr.max().axis(1).where_mask(&negative).compute(),
+            vec![-2.0, -4.0]
+        );
+        assert_eq!(
+            arr.min()
+                .axis(0)
+                .where_mask(&negative)
+                .initial(0.0)
+                .compute(),
+            vec![-4.0, -2.0, -6.0]
+        );
+        assert_vec_approx_eq(
+            arr.mean().axis(1).where_mask(&negative).compute(),
+            vec![-2.0, -5.0],
+        );
+
+        // `initial` takes part in max and min, but only stands in for empty lanes in mean.
+        assert_eq!(arr.max().initial(10.0).compute(), vec![10.0]);
+        assert_eq!(arr.min().axis(1).initial(-5.0).compute(), vec![-5.0, -6.0]);
+        assert_eq!(arr.mean().initial(10.0).compute(), vec![-0.5]);
+
+        let none = arr.map(|_| false);
+        assert_eq!(
+            arr.mean().where_mask(&none).initial(0.0).compute(),
+            vec![0.0]
+        );
+        assert!(matches!(
+            arr.max().axis(0).where_mask(&none).try_compute(),
+            Err(ArrayError::EmptyArray)
+        ));
+        assert!(matches!(
+            arr.min().where_mask(&arr![[true]]).try_compute(),
+            Err(ArrayError::ShapeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn masked_max_skips_nan_outside_mask() {
+        let arr = arr![[1.0, f64::NAN], [3.0, 2.0]];
+        let finite = arr.map(|x| !x.is_nan());
+
+        assert_eq!(
+            arr.max().axis(1).where_mask(&finite).compute(),
+            vec![1.0, 3.0]
+        );
+        assert!(arr.max().axis(1).initial(0.0).compute()[0].is_nan());
+        assert_eq!(
+            arr.max()
+                .axis(1)
+                .initial(0.0)
+                .nan_policy(NanPolicy::Ignore)
+                .compute(),
+            vec![1.0, 3.0]
+        );
+        assert!(matches!(
+            arr.mean()
+                .where_mask(&arr.map(|_| true))
+                .nan_policy(NanPolicy::Raise)
+                .try_compute(),
+            Err(ArrayError::NanEncountered(_))
+        ));
+    }
+
+    #[test]
+    fn reduce_stat_matches_builders() {
+        let arr = arr![[4, 1, 7], [2, 9, 3]];
+        let to_f64 = |values: Vec<i32>| values.into_iter().map(f64::from).collect::<Vec<_>>();
+
+        for axis in [None, Some(0), Some(1)] {
+            let builder_results = match axis {
+                Some(axis) => [
+                    (Stat::Min, to_f64(arr.min().axis(axis).compute())),
+                    (Stat::Max, to_f64(arr.max().axis(axis).compute())),
+                    (Stat::Mean, arr.mean().axis(axis).compute()),
+                    (Stat::Sum, to_f64(arr.sum().axis(axis).compute())),
+                    (Stat::Std, arr.std().axis(axis).compute()),
+                    (Stat::Var, arr.var().axis(axis).compute()),
+                    (Stat::Median, arr.median().axis(axis).compute()),
+                ],
+                None => [
+                    (Stat::Min, to_f64(arr.min().compute())),
+                    (Stat::Max, to_f64(arr.max().compute())),
+                    (Stat::Mean, arr.mean().compute()),
+                    (Stat::Sum, to_f64(arr.sum().compute())),
+                    (Stat::Std, arr.std().compute()),
+                    (Stat::Var, arr.var().compute()),
+                    (Stat::Median, arr.median().compute()),
+                ],
+            };
+
+            for (stat, expected) in builder_results {
+                assert_eq!(arr.reduce_stat(stat, axis).unwrap(), expected, "{:?}", stat);
+            }
+        }
+    }
+
+    #[test]
+    fn reduce_stat_nan_and_4d() {
+        let arr = arr![1.0, f64::NAN, 3.0];
+        for stat in [Stat::Min, Stat::Max, Stat::Mean, Stat::Sum] {
+            assert!(arr.reduce_stat(stat, None).unwrap()[0].is_nan(), "{:?}", stat);
+        }
+        assert!(arr.max().compute()[0].is_nan());
+
+        let data: Vec<f64> = (0..16).map(f64::from).collect();
+        let arr = Array::new(data, Shape::new(Ix::<4>::new([2, 2, 2, 2]))).unwrap();
+        assert_eq!(arr.reduce_stat(Stat::Max, None).unwrap(), arr.max().compute());
+        assert_eq!(arr.reduce_stat(Stat::Max, None).unwrap(), vec![15.0]);
+        assert_eq!(
+            arr.reduce_stat(Stat::Min, Some(2)).unwrap(),
+            arr.min().axis(2).compute()
+        );
+        assert_eq!(
+            arr.reduce_stat(Stat::Mean, Some(1)).unwrap(),
+            arr.mean().axis(1).compute()
+        );
+        assert_eq!(arr.reduce_stat(Stat::Sum, None).unwrap(), vec![120.0]);
+    }
+
+    #[test]
+    fn reduce_stat_from_str() {
+        let arr = arr![1.0, 2.0, 3.0, 10.0];
+        let stat: Stat = "median".parse().unwrap();
+        assert_eq!(arr.reduce_stat(stat, None).unwrap(), vec![2.5]);
+        assert_eq!(arr.reduce_stat("sum".parse().unwrap(), None).unwrap(), vec![16.0]);
+        assert!(matches!(
+            "mode".parse::<Stat>(),
+            Err(ArrayError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            arr.reduce_stat(Stat::Max, Some(1)),
+            Err(ArrayError::InvalidAxis(_))
+        ));
+    }
+
+    #[test]
+    fn streaming_reducer_matches_concatenated() {
+        let chunks = [vec![3.5, -1.0, 8.25], vec![], vec![0.5], vec![4.0, 12.0, -7.5, 2.0]];
+        let mut reducer = StreamingReducer::new();
+        for chunk in &chunks {
+            reducer.push(chunk);
+        }
+        let summary = reducer.finish().unwrap();
+
+        let whole = Array::from(chunks.concat());
+        assert_eq!(summary.count, 8);
+        assert_eq!(summary.min, whole.min().compute()[0]);
+        assert_eq!(summary.max, whole.max().compute()[0]);
+        assert!((summary.sum - whole.sum().compute()[0]).abs() < 1e-12);
+        assert!((summary.mean - whole.mean().compute()[0]).abs() < 1e-12);
+    }
+
+    #[test]
+    fn streaming_reducer_integers_and_empty() {
+        let mut reducer = StreamingReducer::<i32>::default();
+        assert!(matches!(reducer.finish(), Err(ArrayError::EmptyArray)));
+
+        let data = test_util::sequential_array([10, 10]);
+        for row in data.data().chunks(10) {
+            let row: Vec<i32> = row.iter().map(|&x| x as i32).collect();
+            reducer.push(&row);
+        }
+        let summary = reducer.finish().unwrap();
+        assert_eq!((summary.min, summary.max), (0.0, 99.0));
+        assert_eq!(summary.sum, 4950.0);
+        assert_eq!(summary.mean, 49.5);
+
+        let mut reducer = StreamingReducer::new();
+        reducer.push(&[1.0, f64::NAN]);
+        reducer.push(&[2.0]);
+        let summary = reducer.finish().unwrap();
+        assert!(summary.min.is_nan() && summary.max.is_nan() && summary.mean.is_nan());
+    }
+
+    #[test]
+    fn arange_i64_half_open() {
+        let arr = arange_i64(0, 10, 2).unwrap();
+        assert_eq!(arr, arr![0i64, 2, 4, 6, 8]);
+        assert_eq!(arange_i64(0, 9, 3).unwrap().data(), &vec![0, 3, 6]);
+        assert_eq!(arange_i64(0, 10, 3).unwrap().data(), &vec![0, 3, 6, 9]);
+        assert_eq!(arange_i64(5, 0, -2).unwrap().data(), &vec![5, 3, 1]);
+        assert_eq!(arange_i64(3, 3, 1).unwrap().shape().dims(), &[0]);
+    }
+
+    #[test]
+    fn arange_i64_invalid_step() {
+        assert!(matches!(arange_i64(0, 10, 0), Err(ArrayError::InvalidArgument(_))));
+        assert!(matches!(arange_i64(0, 10, -1), Err(ArrayError::InvalidArgument(_))));
+        assert!(matches!(arange_i64(10, 0, 1), Err(ArrayError::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn arange_extreme_bounds() {
+        let arr = arange_i64(i64::MIN, i64::MAX, 1 << 62).unwrap();
+        assert_eq!(arr.data(), &vec![i64::MIN, -(1 << 62), 0, 1 << 62]);
+        let arr = arange_i64(i64::MAX, i64::MIN, i64::MIN).unwrap();
+        assert_eq!(arr.data(), &vec![i64::MAX, -1]);
+
+        assert!(matches!(
+            arange_f64(0.0, 1e300, 1e-300),
+            Err(ArrayError::Overflow(_))
+        ));
+        assert!(matches!(
+            arange_f64(-1e308, 1e308, 1.0),
+            Err(ArrayError::Overflow(_))
+        ));
+    }
+
+    #[test]
+    fn arange_f64_half_open() {
+        assert_eq!(arange_f64(0.0, 1.0, 0.25).unwrap().data(), &vec![0.0, 0.25, 0.5, 0.75]);
+        assert_eq!(arange_f64(1.0, 0.0, -0.5).unwrap().data(), &vec![1.0, 0.5]);
+        assert_eq!(arange_f64(0.0, 1.0, 0.3).unwrap().data().len(), 4);
+
+        assert!(matches!(arange_f64(0.0, 1.0, 0.0), Err(ArrayError::InvalidArgument(_))));
+        assert!(matches!(arange_f64(0.0, 1.0, -0.1), Err(ArrayError::InvalidArgument(_))));
+        assert!(matches!(
+            arange_f64(0.0, f64::INFINITY, 1.0),
+            Err(ArrayError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn try_transpose_2d() {
+        let arr = arr![[1, 2, 3], [4, 5, 6]];
+        assert_eq!(arr.try_transpose().unwrap(), arr.transpose());
+
+        let dynamic = arr.squeeze();
+        let transposed = dynamic.try_transpose().unwrap();
+        assert_eq!(transposed.shape().dims(), &[3, 2]);
+        assert_eq!(transposed.data(), &vec![1, 4, 2, 5, 3, 6]);
+    }
+
+    #[test]
+    fn try_transpose_other_ranks() {
+        for err in [
+            arr![1, 2, 3].try_transpose().unwrap_err(),
+            arr![[[1, 2], [3, 4]]].try_transpose().unwrap_err(),
+        ] {
+            assert!(matches!(err, ArrayError::UnimplementedDimension(_)));
+            assert!(err.to_string().contains("permute_axes"));
+        }
+        assert!(arr![[[1, 2], [3, 4]]]
+            .try_transpose()
+            .unwrap_err()
+            .to_string()
+            .contains("has 3 dimensions"));
+    }
+
+    #[test]
+    fn linspace_includes_endpoints() {
+        let arr = linspace(0.0, 1.0, 5).unwrap();
+        assert_eq!(arr.data(), &vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+
+        let arr = linspace(2.0, -1.0, 4).unwrap();
+        assert_eq!(arr.data(), &vec![2.0, 1.0, 0.0, -1.0]);
+
+        let arr = linspace(0.0, 0.3, 7).unwrap();
+        assert_eq!(arr.data()[6], 0.3);
+
+        assert_eq!(linspace(3.0, 10.0, 1).unwrap().data(), &vec![3.0]);
+    }
+
+    #[test]
+    fn linspace_without_endpoint() {
+        let arr = linspace_endpoint(0.0, 1.0, 4, false).unwrap();
+        assert_eq!(arr.data(), &vec![0.0, 0.25, 0.5, 0.75]);
+        assert_eq!(linspace_endpoint(0.0, 1.0, 1, false).unwrap().data(), &vec![0.0]);
+        assert_eq!(
+            linspace_endpoint(0.0, 1.0, 5, true).unwrap(),
+            linspace(0.0, 1.0, 5).unwrap()
+        );
+    }
+
+    #[test]
+    fn linspace_invalid_arguments() {
+        assert!(matches!(linspace(0.0, 1.0, 0), Err(ArrayError::InvalidArgument(_))));
+        assert!(matches!(
+            linspace(f64::NAN, 1.0, 3),
+            Err(ArrayError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn eye_square() {
+        let identity = eye::<i64>(3);
+        assert_eq!(identity.shape().dims(), &[3, 3]);
+        assert_eq!(identity.data(), &vec![1, 0, 0, 0, 1, 0, 0, 0, 1]);
+
+        let a = arr![[1.5, 2.0], [-3.0, 4.0]];
+        assert_eq!(a.matmul(&eye::<f64>(2)).unwrap(), a);
+        assert_eq!(eye::<i32>(0).data().len(), 0);
+    }
+
+    #[test]
+    fn eye_rect_non_square() {
+        let wide = eye_rect::<i32>(2, 3);
+        assert_eq!(wide.shape().dims(), &[2, 3]);
+        assert_eq!(wide.data(), &vec![1, 0, 0, 0, 1, 0]);
+
+        let tall = eye_rect::<f64>(3, 2);
+        assert_eq!(tall.data(), &vec![1.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+        assert_eq!(eye_rect::<i64>(4, 4), eye::<i64>(4));
+    }
+
+    #[test]
+    fn dot_small() {
+        assert_eq!(arr![1, 2, 3].dot(&arr![4, 5, 6]).unwrap(), 32);
+        assert_eq!(arr![0.5, 1.5].dot(&arr![2.0, 4.0]).unwrap(), 7.0);
+
+        let empty: Array<i32, Ix<1>> = Array::from(Vec::new());
+        assert_eq!(empty.dot(&empty).unwrap(), 0);
+    }
+
+    #[test]
+    fn dot_length_mismatch() {
+        assert!(matches!(
+            arr![1, 2, 3].dot(&arr![1, 2]),
+            Err(ArrayError::DimensionMismatch {
+                expected: 3,
+                actual: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn dot_f64_small() {
+        let a = arr![1, 2, 3];
+        let b = arr![4, 5, 6];
+        assert_eq!(a.dot_f64(&b).unwrap(), 32.0);
+    }
+
+    #[test]
+    fn dot_f64_avoids_i64_overflow() {
+        let a = arr![i64::MAX, i64::MAX];
+        let b = arr![2i64, 2];
+        assert!(a.data()[0].checked_mul(b.data()[0]).is_none());
+
+        let result = a.dot_f64(&b).unwrap();
+        assert!(result.is_finite());
+        assert_eq!(result, 4.0 * i64::MAX as f64);
+    }
+
+    #[test]
+    fn dot_f64_length_mismatch() {
+        let a = arr![1.0, 2.0];
+        let b = arr![1.0, 2.0, 3.0];
+        assert!(matches!(
+            a.dot_f64(&b),
+            Err(ArrayError::DimensionMismatch {
+                expected: 2,
+                actual: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn matmul_f64_small() {
+        let a = arr![[1, 2, 3], [4, 5, 6]];
+        let b = arr![[7, 8], [9, 10], [11, 12]];
+        let c = a.matmul_f64(&b).unwrap();
+        assert_eq!(c.shape().dims(), &[2, 2]);
+        assert_eq!(c.data(), &vec![58.0, 64.0, 139.0, 154.0]);
+    }
+
+    #[test]
+    fn matmul_f64_avoids_i64_overflow() {
+        let a = arr![[i64::MAX, i64::MAX], [1, 1]];
+        let b = arr![[3i64, 0], [3, 1]];
+        let c = a.matmul_f64(&b).unwrap();
+        assert!(c.data().iter().all(|x| x.is_finite()));
+        assert_eq!(c.data()[0], 6.0 * i64::MAX as f64);
+        assert_eq!(c.data()[2..], [6.0, 1.0]);
+    }
+
+    #[test]
+    fn matmul_f64_inner_mismatch() {
+        let a = arr![[1.0, 2.0], [3.0, 4.0]];
+        let b = arr![[1.0, 2.0, 3.0]];
+        assert!(matches!(
+            a.matmul_f64(&b),
+            Err(ArrayError::DimensionMismatch {
+                expected: 2,
+                actual: 1
+            })
+        ));
+        assert!(matches!(
+            a.matmul(&b),
+            Err(ArrayError::DimensionMismatch {
+                expected: 2,
+                actual: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn pad_constant_2d() {
+        let arr = arr![[1, 2], [3, 4]];
+        let padded = arr.pad(1, PadMode::Constant(0)).unwrap();
+        assert_eq!(padded.shape().dims(), &[4, 4]);
+        assert_eq!(
+            padded.data(),
+            &vec![0, 0, 0, 0, 0, 1, 2, 0, 0, 3, 4, 0, 0, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn pad_edge_1d() {
+        let arr = arr![1, 2, 3];
+        let padded = arr.pad(2, PadMode::Edge).unwrap();
+        assert_eq!(padded.shape().dims(), &[7]);
+        assert_eq!(padded.data(), &vec![1, 1, 1, 2, 3, 3, 3]);
+    }
+
+    #[test]
+    fn pad_reflect_1d() {
+        let arr = arr![1, 2, 3];
+        let padded = arr.pad(2, PadMode::Reflect).unwrap();
+        assert_eq!(padded.shape().dims(), &[7]);
+        assert_eq!(padded.data(), &vec![3, 2, 1, 2, 3, 2, 1]);
+    }
+
+    #[test]
+    fn pad_edge_and_reflect_2d() {
+        let arr = arr![[1, 2, 3], [4, 5, 6]];
+        let edge = arr.pad(1, PadMode::Edge).unwrap();
+        assert_eq!(edge.shape().dims(), &[4, 5]);
+        assert_eq!(
+            edge.data(),
+            &vec![1, 1, 2, 3, 3, 1, 1, 2, 3, 3, 4, 4, 5, 6, 6, 4, 4, 5, 6, 6]
+        );
+
+        let reflect = arr.pad(1, PadMode::Reflect).unwrap();
+        assert_eq!(
+            reflect.data(),
+            &vec![5, 4, 5, 6, 5, 2, 1, 2, 3, 2, 5, 4, 5, 6, 5, 2, 1, 2, 3, 2]
+        );
+    }
+
+    #[test]
+    fn pad_reflect_too_wide() {
+        let arr = arr![1, 2, 3];
+        assert!(matches!(
+            arr.pad(3, PadMode::Reflect),
+            Err(ArrayError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn set_at_scatter_2d() {
+        let mut arr = arr![[0, 0, 0], [0, 0, 0]];
+        arr.set_at(&[vec![0, 0], vec![1, 2], vec![0, 1]], &[7, 8, 9])
+            .unwrap();
+        assert_eq!(arr.data(), &vec![7, 9, 0, 0, 0, 8]);
+    }
+
+    #[test]
+    fn set_where_negatives_to_zero() {
+        let mut arr = arr![[1, -2, 3], [-4, 5, -6]];
+        arr.set_where(|&x| x < 0, 0);
+        assert_eq!(arr.shape().dims(), &[2, 3]);
+        assert_eq!(arr.data(), &vec![1, 0, 3, 0, 5, 0]);
+    }
+
+    #[test]
+    fn try_add_elementwise() {
+        let a = arr![[1, 2, 3], [4, 5, 6]];
+        let b = arr![[10, 20, 30], [40, 50, 60]];
+        let sum = a.try_add(&b).unwrap();
+        assert_eq!(sum.shape().dims(), &[2, 3]);
+        assert_eq!(sum.data(), &vec![11, 22, 33, 44, 55, 66]);
+    }
+
+    #[test]
+    fn try_add_assign_elementwise() {
+        let mut a = arr![0.5, 1.5];
+        a.try_add_assign(&arr![1.0, -1.0]).unwrap();
+        assert_eq!(a.data(), &vec![1.5, 0.5]);
+    }
+
+    #[test]
+    fn broadcast_shapes_rules() {
+        assert_eq!(broadcast_shapes(&[3, 1], &[1, 3]).unwrap(), vec![3, 3]);
+        assert_eq!(broadcast_shapes(&[2, 3, 4], &[4]).unwrap(), vec![2, 3, 4]);
+        assert_eq!(
+            broadcast_shapes(&[5, 1, 4], &[3, 1]).unwrap(),
+            vec![5, 3, 4]
+        );
+        assert!(matches!(
+            broadcast_shapes(&[3], &[4]),
+            Err(ArrayError::DimensionMismatch {
+                expected: 3,
+                actual: 4
+            })
+        ));
+    }
+
+    #[test]
+    fn broadcast_iter_row_to_matrix() {
+        let row = arr![[1, 2, 3]];
+        let values: Vec<i32> = row.broadcast_iter(&[4, 3]).unwrap().copied().collect();
+        assert_eq!(values, vec![1, 2, 3, 1, 2, 3, 1, 2, 3, 1, 2, 3]);
+
+        let column = arr![[1], [2]];
+        let values: Vec<i32> = column.broadcast_iter(&[2, 2, 3]).unwrap().copied().collect();
+        assert_eq!(values, vec![1, 1, 1, 2, 2, 2, 1, 1, 1, 2, 2, 2]);
+
+        let total: i32 = row.broadcast_iter(&[4, 3]).unwrap().sum();
+        assert_eq!(total, 24);
+    }
+
+    #[test]
+    fn broadcast_iter_incompatible() {
+        let row = arr![[1, 2, 3]];
+        assert!(matches!(
+            row.broadcast_iter(&[4, 2]),
+            Err(ArrayError::ShapeMismatch { .. })
+        ));
+        assert!(row.broadcast_iter(&[3]).is_err());
+        assert!(arr![[1, 2], [3, 4]].broadcast_iter(&[1, 2]).is_err());
+    }
+
+    #[test]
+    fn custom_dimension_without_with_dims() {
+        #[derive(Clone)]
+        struct Fixed(Vec<usize>);
+
+        impl Dimension for Fixed {
+            fn ndim(&self) -> usize {
+                self.0.len()
+            }
+
+            fn size(&self) -> usize {
+                self.0.iter().product()
+            }
+
+            fn dims(&self) -> &[usize] {
+                &self.0
+            }
+        }
+
+        let row = Array::new(vec![1, 2, 3], Shape::new(Fixed(vec![1, 3]))).unwrap();
+        let column = Array::new(vec![10, 20], Shape::new(Fixed(vec![2, 1]))).unwrap();
+        assert_eq!(row.try_add(&row).unwrap().data(), &vec![2, 4, 6]);
+        assert!(matches!(
+            row.try_add(&column),
+            Err(ArrayError::ShapeMismatch { .. })
+        ));
+        assert!(matches!(
+            row.try_transpose(),
+            Err(ArrayError::UnimplementedDimension(_))
+        ));
+    }
+
+    #[test]
+    fn broadcast_column_plus_row() {
+        let column = arr![[1], [2], [3]];
+        let row = arr![[10, 20, 30]];
+
+        let sum = &column + &row;
+        assert_eq!(sum.shape().dims(), &[3, 3]);
+        assert_eq!(sum.data(), &vec![11, 21, 31, 12, 22, 32, 13, 23, 33]);
+        assert_eq!(row.try_add(&column).unwrap(), sum);
+
+        let product = column.try_mul(&row).unwrap();
+        assert_eq!(product.data(), &vec![10, 20, 30, 20, 40, 60, 30, 60, 90]);
+    }
+
+    #[test]
+    fn broadcast_3d_against_row() {
+        let arr = arr![[[1.0, 2.0], [3.0, 4.0]], [[5.0, 6.0], [7.0, 8.0]]];
+        let scale = arr![[[2.0, 0.5]]];
+        let result = &arr * &scale;
+        assert_eq!(result.shape().dims(), &[2, 2, 2]);
+        assert_eq!(
+            result.data(),
+            &vec![2.0, 1.0, 6.0, 2.0, 10.0, 3.0, 14.0, 4.0]
+        );
+        assert_eq!(
+            (&arr - &arr![[[1.0], [2.0]]]).data()[..4],
+            [0.0, 1.0, 1.0, 2.0]
+        );
+    }
+
+    #[test]
+    fn try_add_shape_mismatch_reports_both_shapes() {
+        let mut a = arr![[1, 2, 3], [4, 5, 6]];
+        let b = arr![[1, 2], [3, 4], [5, 6]];
+
+        let err = a.try_add(&b).unwrap_err();
+        assert!(matches!(
+            &err,
+            ArrayError::ShapeMismatch { lhs, rhs } if lhs == &[2, 3] && rhs == &[3, 2]
+        ));
+        assert!(err.to_string().contains("[2, 3]"));
+        assert!(err.to_string().contains("[3, 2]"));
+
+        assert!(a.try_add_assign(&b).is_err());
+        assert_eq!(a.data(), &vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn scalar_arithmetic() {
+        let arr = arr![1, 2, 3];
+        assert_eq!(arr.mul_scalar(10).data(), &vec![10, 20, 30]);
+        assert_eq!(arr.add_scalar(5).data(), &vec![6, 7, 8]);
+        assert_eq!(arr.sub_scalar(1).data(), &vec![0, 1, 2]);
+
+        let arr = arr![[2.0, 4.0], [6.0, 8.0]];
+        let halved = arr.div_scalar(2.0);
+        assert_eq!(halved.shape().dims(), &[2, 2]);
+        assert_eq!(halved.data(), &vec![1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn powf_promotes_to_f64() {
+        assert_eq!(arr![2, 3].powf(2.0).data(), &vec![4.0, 9.0]);
+
+        let arr = arr![[4.0, 9.0], [16.0, 0.25]];
+        let roots = arr.powf(0.5);
+        assert_eq!(roots.shape().dims(), &[2, 2]);
+        assert_vec_approx_eq(roots.data().clone(), vec![2.0, 3.0, 4.0, 0.5]);
+    }
+
+    #[test]
+    fn powi_keeps_element_type() {
+        let arr: Array<i64, Ix<2>> = arr![[1, -2], [3, 4]];
+        assert_eq!(arr.powi(3).data(), &vec![1, -8, 27, 64]);
+        assert_eq!(arr.powi(0).data(), &vec![1, 1, 1, 1]);
+        assert_eq!(arr![1.5, 2.0].powi(2).data(), &vec![2.25, 4.0]);
+    }
+
+    #[test]
+    fn scale_assign_after_ones() {
+        let mut arr = arr![[0.0, 0.0, 0.0], [0.0, 0.0, 0.0]];
+        arr.ones();
+        arr.scale_assign(2.5);
+        assert_eq!(arr.shape().dims(), &[2, 3]);
+        assert_eq!(arr.data(), &vec![2.5; 6]);
+    }
+
+    #[test]
+    fn elementwise_operators_i64() {
+        let a: Array<i64, Ix<2>> = arr![[1, 2], [3, 4]];
+        let b: Array<i64, Ix<2>> = arr![[10, 20], [30, 40]];
+
+        assert_eq!((&a + &b).data(), &vec![11, 22, 33, 44]);
+        assert_eq!((&b - &a).data(), &vec![9, 18, 27, 36]);
+        assert_eq!((&a * &b).data(), &vec![10, 40, 90, 160]);
+        assert_eq!((&b / &a).data(), &vec![10, 10, 10, 10]);
+        assert_eq!((a.map(|x| x) + b).shape().dims(), &[2, 2]);
+    }
+
+    #[test]
+    fn elementwise_operators_f64() {
+        let a = arr![[1.0, 2.0], [3.0, 4.0]];
+        let b = arr![[0.5, 0.5], [2.0, 8.0]];
+
+        assert_eq!((&a + &b).data(), &vec![1.5, 2.5, 5.0, 12.0]);
+        assert_eq!((&a - b.map(|x| x)).data(), &vec![0.5, 1.5, 1.0, -4.0]);
+        assert_eq!((a.map(|x| x) * &b).data(), &vec![0.5, 1.0, 6.0, 32.0]);
+        assert_eq!((a / b).data(), &vec![2.0, 4.0, 1.5, 0.5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot add arrays")]
+    fn elementwise_operator_shape_mismatch_panics() {
+        let _ = &arr![[1, 2, 3]] + &arr![[1, 2], [3, 4]];
+    }
+
+    #[test]
+    fn get_by_index() {
+        let arr = arr![[1, 2], [3, 4]];
+        assert_eq!(arr.get([1, 0]), Some(&3));
+        assert_eq!(arr.get([0, 1]), Some(&2));
+        assert_eq!(arr.get([2, 0]), None);
+        assert_eq!(arr.get([0, 2]), None);
+
+        let arr = arr![[[1, 2], [3, 4]], [[5, 6], [7, 8]]];
+        assert_eq!(arr.get([1, 0, 1]), Some(&6));
+        assert_eq!(*arr.at([1, 1, 0]), 7);
+    }
+
+    #[test]
+    fn render_matches_layout() {
+        let arr = arr![[1i64, 20], [300, 4]];
+        assert_eq!(arr.visualize().render(), "[\n   [1  , 20]\n   [300, 4 ]\n]\n");
+        assert_eq!(arr![1.5, 2.25].visualize().decimal_points(2).render(), "[1.50, 2.25]\n");
+    }
+
+    #[test]
+    fn render_zero_length_axes() {
+        let arr = Array::<i64, Ix<3>>::from_elem(&[2, 0, 3], 0).unwrap();
+        assert_eq!(arr.visualize().render(), "[\n   []\n   []\n]\n");
+
+        let arr = Array::<i64, Ix<3>>::from_elem(&[2, 2, 0], 0).unwrap();
+        let block = "   [\n      []\n      []\n   ]\n";
+        assert_eq!(arr.visualize().render(), format!("[\n{block}{block}]\n"));
+
+        let arr = Array::<f64, Ix<3>>::from_elem(&[0, 2, 3], 0.0).unwrap();
+        assert_eq!(arr.visualize().render(), "[]\n");
+
+        let arr = Array::<i64, Ix<2>>::from_elem(&[2, 0], 0).unwrap();
+        assert_eq!(arr.visualize().render(), "[\n   []\n   []\n]\n");
+        let arr = Array::<i64, Ix<1>>::from(Vec::new());
+        assert_eq!(arr.visualize().render(), "[]\n");
+    }
+
+    #[test]
+    fn iter_and_iter_mut() {
+        let mut arr = arr![[1, 2], [3, 4]];
+        assert_eq!(arr.iter().sum::<i32>(), 10);
+
+        for value in arr.iter_mut() {
+            *value *= 10;
+        }
+        assert_eq!(arr.iter().copied().collect::<Vec<_>>(), vec![10, 20, 30, 40]);
+    }
+
+    #[test]
+    fn indexed_iter_yields_coordinates() {
+        let arr = arr![[1, 0, 2], [0, 3, 0]];
+        let items: Vec<([usize; 2], &i32)> = arr.indexed_iter().collect();
+        assert_eq!(items[0], ([0, 0], &1));
+        assert_eq!(items[4], ([1, 1], &3));
+        assert_eq!(items.len(), 6);
+
+        let sparse: Vec<([usize; 2], i32)> = arr
+            .indexed_iter()
+            .filter(|(_, &value)| value != 0)
+            .map(|(index, &value)| (index, value))
+            .collect();
+        assert_eq!(sparse, vec![([0, 0], 1), ([0, 2], 2), ([1, 1], 3)]);
+
+        let cube = arr![[[1, 2], [3, 4]], [[5, 6], [7, 8]]];
+        for (index, &value) in cube.indexed_iter() {
+            assert_eq!(cube[index], value);
+        }
+        assert_eq!(cube.indexed_iter().last(), Some(([1, 1, 1], &8)));
+    }
+
+    #[test]
+    fn set_single_element() {
+        let mut arr = zeros!(i32, 3);
+        arr.set([1], 5).unwrap();
+        assert_eq!(arr.data(), &vec![0, 5, 0]);
+
+        let mut arr = zeros!(i32, 2, 3);
+        arr.set([1, 2], 7).unwrap();
+        arr.set([0, 0], 1).unwrap();
+        assert_eq!(arr.data(), &vec![1, 0, 0, 0, 0, 7]);
+
+        let mut arr = zeros!(f64, 2, 2, 2);
+        arr.set([1, 0, 1], 2.5).unwrap();
+        assert_eq!(arr.get([1, 0, 1]), Some(&2.5));
+        assert_eq!(arr.data().iter().filter(|&&x| x != 0.0).count(), 1);
+    }
+
+    #[test]
+    fn set_out_of_bounds() {
+        let mut arr = zeros!(i32, 2, 3);
+        let err = arr.set([2, 1], 9).unwrap_err();
+        assert!(matches!(err, ArrayError::IndexOutOfBounds(_)));
+        assert!(err.to_string().contains("[2, 1]"));
+        assert!(err.to_string().contains("[2, 3]"));
+        assert!(arr.data().iter().all(|&x| x == 0));
+
+        let mut arr = zeros!(i32, 4);
+        assert!(arr.set([4], 1).is_err());
+    }
+
+    #[test]
+    fn index_operator() {
+        let mut arr = arr![[1, 2], [3, 4]];
+        assert_eq!(arr[[1, 0]], 3);
+
+        arr[[0, 1]] += 10;
+        arr[[1, 1]] = 0;
+        assert_eq!(arr.data(), &vec![1, 12, 3, 0]);
+
+        let mut cube = arr![[[1, 2], [3, 4]], [[5, 6], [7, 8]]];
+        cube[[1, 0, 1]] *= 2;
+        assert_eq!(cube[[1, 0, 1]], 12);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds: Index [0, 3] is out of bounds for array with shape [2, 2]")]
+    fn index_out_of_bounds_panics() {
+        let arr = arr![[1, 2], [3, 4]];
+        let _ = arr[[0, 3]];
+    }
+
+    #[test]
+    #[should_panic(expected = "Index [2, 1] is out of bounds for array with shape [2, 2]")]
+    fn index_mut_out_of_bounds_panics() {
+        let mut arr = arr![[1, 2], [3, 4]];
+        arr[[2, 1]] = 5;
+    }
+
+    #[test]
+    fn get_mut_by_index() {
+        let mut arr = arr![[1, 2], [3, 4]];
+        *arr.get_mut([1, 0]).unwrap() = 30;
+        assert_eq!(arr.data(), &vec![1, 2, 30, 4]);
+        assert!(arr.get_mut([0, 5]).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Index [2, 0] is out of bounds for array with shape [2, 2]")]
+    fn at_out_of_bounds_panics() {
+        let arr = arr![[1, 2], [3, 4]];
+        arr.at([2, 0]);
+    }
+
+    #[test]
+    fn set_at_out_of_bounds() {
+        let mut arr = arr![[1, 2], [3, 4]];
+        let result = arr.set_at(&[vec![0, 0], vec![2, 0]], &[10, 20]);
+        assert!(matches!(result, Err(ArrayError::IndexOutOfBounds(_))));
+        assert_eq!(arr.data(), &vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn set_at_length_mismatch() {
+        let mut arr = arr![1, 2, 3];
+        assert!(matches!(
+            arr.set_at(&[vec![0]], &[1, 2]),
+            Err(ArrayError::DimensionMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn gather_flat_2d() {
+        let arr = arr![[10, 20, 30], [40, 50, 60]];
+        let gathered = arr.gather_flat(&[5, 0, 3, 3]).unwrap();
+        assert_eq!(gathered.shape().dims(), &[4]);
+        assert_eq!(gathered.data(), &vec![60, 10, 40, 40]);
+    }
+
+    #[test]
+    fn gather_flat_out_of_bounds() {
+        let arr = arr![[1.0, 2.0], [3.0, 4.0]];
+        assert!(matches!(
+            arr.gather_flat(&[1, 4]),
+            Err(ArrayError::IndexOutOfBounds(_))
+        ));
+    }
+
+    #[test]
+    fn meshgrid_xy() {
+        let x = arr![1, 2, 3];
+        let y = arr![4, 5];
+        let (xx, yy) = Array::meshgrid(&x, &y);
+
+        assert_eq!(xx.shape().dims(), &[2, 3]);
+        assert_eq!(yy.shape().dims(), &[2, 3]);
+        assert_eq!(xx.data(), &vec![1, 2, 3, 1, 2, 3]);
+        assert_eq!(yy.data(), &vec![4, 4, 4, 5, 5, 5]);
+    }
+
+    #[test]
+    fn stack_columns_feature_matrix() {
+        let a = arr![1, 2, 3, 4];
+        let b = arr![5, 6, 7, 8];
+        let c = arr![9, 10, 11, 12];
+        let stacked = Array::stack_columns(&[a, b, c]).unwrap();
+
+        assert_eq!(stacked.shape().dims(), &[4, 3]);
+        assert_eq!(
+            stacked.data(),
+            &vec![1, 5, 9, 2, 6, 10, 3, 7, 11, 4, 8, 12]
+        );
+    }
+
+    #[test]
+    fn stack_columns_length_mismatch() {
+        let a = arr![1.0, 2.0, 3.0];
+        let b = arr![4.0, 5.0];
+        assert!(matches!(
+            Array::stack_columns(&[a, b]),
+            Err(ArrayError::DimensionMismatch {
+                expected: 3,
+                actual: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn diagflat_1d() {
+        let arr = arr![1, 2, 3];
+        let diag = arr.diagflat();
+        assert_eq!(diag.shape().dims(), &[3, 3]);
+        assert_eq!(diag.data(), &vec![1, 0, 0, 0, 2, 0, 0, 0, 3]);
+    }
+
+    #[test]
+    fn dedup_rows_consecutive() {
+        let arr = arr![[1, 2], [1, 2], [3, 4], [1, 2], [3, 4], [3, 4]];
+        let deduped = arr.dedup_rows();
+        assert_eq!(deduped.shape().dims(), &[4, 2]);
+        assert_eq!(deduped.data(), &vec![1, 2, 3, 4, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn dedup_rows_all_identical() {
+        let arr = arr![[0.5, 1.5, 2.5], [0.5, 1.5, 2.5]];
+        let deduped = arr.dedup_rows();
+        assert_eq!(deduped.shape().dims(), &[1, 3]);
+        assert_eq!(deduped.data(), &vec![0.5, 1.5, 2.5]);
+    }
+
+    #[test]
+    fn block_2x2_grid() {
+        let a = arr![[1]];
+        let b = arr![[2, 3]];
+        let c = arr![[4], [7]];
+        let d = arr![[5, 6], [8, 9]];
+        let assembled = Array::block(&[vec![a, b], vec![c, d]]).unwrap();
+
+        assert_eq!(assembled.shape().dims(), &[3, 3]);
+        assert_eq!(assembled.data(), &vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn block_incompatible() {
+        let rows_differ = Array::block(&[vec![arr![[1, 2]], arr![[3], [4]]]]);
+        assert!(matches!(
+            rows_differ,
+            Err(ArrayError::DimensionMismatch { .. })
+        ));
+
+        let cols_differ = Array::block(&[vec![arr![[1, 2]]], vec![arr![[3, 4, 5]]]]);
+        assert!(matches!(
+            cols_differ,
+            Err(ArrayError::DimensionMismatch {
+                expected: 2,
+                actual: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn flip_view_matches_owned_flip() {
+        let arr = arr![[1, 2, 3], [4, 5, 6]];
+        let flipped = arr.flip();
+        let view = arr.flip_view();
+
+        assert_eq!(flipped.data(), &vec![6, 5, 4, 3, 2, 1]);
+        assert_eq!(view.shape().dims(), &[2, 3]);
+        assert_eq!(view.strides(), &[-3, -1]);
+        assert_eq!(view.iter().len(), 6);
+        assert!(view.iter().eq(flipped.data().iter()));
+    }
+
+    #[test]
+    fn flip_view_reduction() {
+        let arr = arr![[[1.5, -2.0], [3.0, 4.5]], [[0.5, 7.0], [-1.0, 2.0]]];
+        let view = arr.flip_view();
+
+        let max = view.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        assert_eq!(vec![max], arr.max().compute());
+        assert_eq!(view.iter().next(), Some(&2.0));
+        assert!(view.iter().eq(arr.flip().data().iter()));
+    }
+
+    #[test]
+    fn view_reductions_match_owned() {
+        let arr = arr![[[1.5, -2.0], [3.0, 4.5]], [[0.5, 7.0], [-1.0, 2.0]]];
+        let flipped = arr.flip();
+        let view = arr.flip_view();
+
+        assert_eq!(view.sum(None).unwrap(), flipped.sum().compute());
+        assert_eq!(view.max(None).unwrap(), flipped.max().compute());
+        for axis in 0..3 {
+            assert_eq!(
+                view.sum(Some(axis)).unwrap(),
+                flipped.sum().axis(axis).compute()
+            );
+            assert_eq!(
+                view.max(Some(axis)).unwrap(),
+                flipped.max().axis(axis).compute()
+            );
+            assert_eq!(
+                view.min(Some(axis)).unwrap(),
+                flipped.min().axis(axis).compute()
+            );
+            assert_vec_approx_eq(
+                view.mean(Some(axis)).unwrap(),
+                flipped.mean().axis(axis).compute(),
+            );
+        }
+
+        let matrix = arr![[1, 8, 3], [6, 2, 4]];
+        let transposed = matrix.transpose();
+        for axis in [None, Some(0), Some(1)] {
+            assert_eq!(
+                matrix.t().sum(axis).unwrap(),
+                transposed.sum_compute(axis).unwrap()
+            );
+            assert_eq!(
+                matrix.t().max(axis).unwrap(),
+                transposed.max_compute(axis).unwrap()
+            );
+            assert_eq!(
+                matrix.t().min(axis).unwrap(),
+                transposed.min_compute(axis).unwrap()
+            );
+            assert_eq!(
+                matrix.t().mean(axis).unwrap(),
+                transposed.mean_compute(axis).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn view_reduction_nan_and_errors() {
+        let arr = arr![[1.0, f64::NAN], [3.0, 2.0]];
+        let max = arr.flip_view().max(Some(0)).unwrap();
+        assert!(max[0].is_nan());
+        assert_eq!(max[1], 3.0);
+        assert!(arr.t().min(Some(0)).unwrap()[0].is_nan());
+
+        assert!(matches!(
+            arr.flip_view().sum(Some(2)),
+            Err(ArrayError::InvalidAxis(_))
+        ));
+        let empty: Array<f64, Ix<1>> = Array::from(vec![]);
+        assert!(matches!(
+            empty.flip_view().mean(None),
+            Err(ArrayError::EmptyArray)
+        ));
+    }
+
+    #[test]
+    fn view_iterates_in_row_major_order() {
+        let arr = arr![[1, 2], [3, 4]];
+        let view = arr.view();
+        assert_eq!(view.strides(), &[2, 1]);
+        assert!(view.iter().eq(arr.data().iter()));
+    }
+
+    #[test]
+    fn view_to_owned() {
+        let arr = arr![[1, 2, 3], [4, 5, 6]];
+
+        let transposed = arr.t().to_owned();
+        let expected = arr.transpose();
+        assert_eq!(transposed.shape().dims(), &[3, 2]);
+        assert_eq!(transposed.data(), expected.data());
+
+        let flipped = arr.flip_view().to_owned();
+        assert_eq!(flipped.shape().dims(), &[2, 3]);
+        for (a, b) in flipped.data().iter().zip(arr.flip().data()) {
+            assert_eq!(a, b);
+        }
+
+        assert_eq!(arr.view().to_owned().data(), arr.data());
+    }
+
+    #[test]
+    fn roundtrip_generated_shapes() {
+        for dims in test_util::shapes_2d(4) {
+            test_util::assert_roundtrip(&test_util::sequential_array(dims));
+            test_util::assert_roundtrip(&test_util::random_array(dims, 42));
+        }
+    }
+
+    #[test]
+    fn roundtrip_edge_shapes() {
+        test_util::assert_roundtrip(&test_util::sequential_array([0, 3]));
+        test_util::assert_roundtrip(&test_util::sequential_array([1, 7]));
+        test_util::assert_roundtrip(&test_util::random_array([16, 1], 7));
+    }
+
+    #[test]
+    fn array_equality() {
+        let a = arr![[1, 2], [3, 4]];
+        assert_eq!(a, arr![[1, 2], [3, 4]]);
+        assert_ne!(a, arr![[1, 2], [3, 5]]);
+        assert_ne!(a.reshape([4]).unwrap().reshape([1, 4]).unwrap(), a.reshape([4, 1]).unwrap());
+    }
+
+    #[test]
+    fn transpose_2d() {
+        let arr = arr![[1, 2, 3], [4, 5, 6]];
+        let transposed = arr.transpose();
+        assert_eq!(transposed.shape().dims(), &[3, 2]);
+        assert_eq!(transposed.data(), &vec![1, 4, 2, 5, 3, 6]);
+
+        let back = transposed.transpose();
+        assert_eq!(back.shape().dims(), &[2, 3]);
+        assert_eq!(back.data(), arr.data());
+    }
+
+    #[test]
+    fn t_alias_matches_transpose() {
+        let arr = arr![[1.5, 2.5], [3.5, 4.5], [5.5, 6.5]];
+        let view = arr.t();
+        assert_eq!(view.shape().dims(), &[2, 3]);
+        assert!(view.iter().eq(arr.transpose().data().iter()));
+        assert_eq!(view.to_owned().t().to_owned().data(), arr.data());
+    }
+
+    #[test]
+    fn as_standard_layout_from_transposed_view() {
+        let arr = arr![[1, 2, 3], [4, 5, 6]];
+        let view = arr.t();
+        assert!(!view.is_standard_layout());
+
+        let contiguous = view.as_standard_layout();
+        assert_eq!(contiguous.shape().dims(), &[3, 2]);
+        assert_eq!(contiguous.data(), &vec![1, 4, 2, 5, 3, 6]);
+        assert!(contiguous.view().is_standard_layout());
+        assert_eq!(contiguous, arr.transpose());
+    }
+
+    #[test]
+    fn as_standard_layout_contiguous_views() {
+        let arr = arr![[1.0, 2.0], [3.0, 4.0]];
+        assert!(arr.view().is_standard_layout());
+        assert_eq!(arr.view().as_standard_layout(), arr);
+
+        let flipped = arr.flip_view();
+        assert!(!flipped.is_standard_layout());
+        assert_eq!(flipped.as_standard_layout(), arr.flip());
+
+        let row = arr![[7, 8, 9]];
+        assert!(row.t().is_standard_layout());
+        assert_eq!(row.t().as_standard_layout().data(), &vec![7, 8, 9]);
+    }
+
+    #[test]
+    fn transposed_view_matmul() {
+        let a = arr![[1, 2, 3], [4, 5, 6]];
+        let b = arr![[7, 8], [9, 10]];
+
+        let view_product = a.t().matmul(&b).unwrap();
+        let owned_product = a.transpose().matmul(&b).unwrap();
+
+        assert_eq!(view_product.shape().dims(), &[3, 2]);
+        assert_eq!(view_product.data(), owned_product.data());
+        assert_eq!(view_product.data(), &vec![43, 48, 59, 66, 75, 84]);
+    }
+
+    #[test]
+    fn fill_diagonal_square() {
+        let mut arr = arr![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+        arr.fill_diagonal(0).unwrap();
+        assert_eq!(arr.data(), &vec![0, 2, 3, 4, 0, 6, 7, 8, 0]);
+    }
+
+    #[test]
+    fn fill_diagonal_non_square() {
+        let mut arr = arr![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+        arr.fill_diagonal(-1.0).unwrap();
+        assert_eq!(arr.shape().dims(), &[2, 3]);
+        assert_eq!(arr.data(), &vec![-1.0, 2.0, 3.0, 4.0, -1.0, 6.0]);
+    }
+
+    #[test]
+    fn matmul_view_operands() {
+        let a = arr![[1.0, 2.0], [3.0, 4.0]];
+        let b = arr![[5.0, 6.0], [7.0, 8.0]];
+
+        let at_bt = a.t().matmul(&b.t()).unwrap();
+        let expected = a.transpose().matmul(&b.transpose()).unwrap();
+        assert_eq!(at_bt.data(), expected.data());
+
+        let a_bt = a.matmul(&b.t()).unwrap();
+        assert_eq!(a_bt.data(), &vec![17.0, 23.0, 39.0, 53.0]);
+    }
+
+    #[test]
+    fn matmul_view_inner_mismatch() {
+        let a = arr![[1, 2, 3], [4, 5, 6]];
+        assert!(matches!(
+            a.t().matmul(&a.t()),
+            Err(ArrayError::DimensionMismatch {
+                expected: 2,
+                actual: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn matmul_2x3_by_3x2() {
+        let a = arr![[1, 2, 3], [4, 5, 6]];
+        let b = arr![[7, 8], [9, 10], [11, 12]];
+
+        let c = a.matmul(&b).unwrap();
+        assert_eq!(c.shape().dims(), &[2, 2]);
+        assert_eq!(c.data(), &vec![58, 64, 139, 154]);
+        assert!(matches!(
+            b.matmul(&b),
+            Err(ArrayError::DimensionMismatch {
+                expected: 2,
+                actual: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn clip_i64() {
+        let arr = arr![-5, 0, 5, 10];
+        let clipped = arr.clip(0, 7).unwrap();
+        assert_eq!(clipped.data(), &vec![0, 0, 5, 7]);
+        assert_eq!(clipped.shape().dims(), &[4]);
+    }
+
+    #[test]
+    fn clip_f64_preserves_nan() {
+        let arr = arr![f64::NAN, -5.0, 100.0];
+        let clipped = arr.clip(0.0, 10.0).unwrap();
+        assert!(clipped.data()[0].is_nan());
+        assert_eq!(clipped.data()[1..], [0.0, 10.0]);
+    }
+
+    #[test]
+    fn clip_arrays_per_element() {
+        let arr = arr![-5, 3, 8, 12];
+        let lo = arr![0, 4, 0, 0];
+        let hi = arr![10, 10, 5, 20];
+        let clipped = arr.clip_arrays(&lo, &hi).unwrap();
+        assert_eq!(clipped.data(), &vec![0, 4, 5, 12]);
+    }
+
+    #[test]
+    fn clip_arrays_shape_mismatch() {
+        let arr = arr![1.0, 2.0, 3.0];
+        let lo = arr![0.0, 0.0];
+        let hi = arr![5.0, 5.0, 5.0];
+        assert!(matches!(
+            arr.clip_arrays(&lo, &hi),
+            Err(ArrayError::ShapeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn clip_arrays_same_size_different_shape() {
+        let arr = arr![[1, 2, 3], [4, 5, 6]];
+        let lo = arr![[0, 0], [0, 0], [0, 0]];
+        let hi = arr![[9, 9, 9], [9, 9, 9]];
 
-        assert_eq!(arr.shape().raw_dim().size(), 12);
-        assert_eq!(arr.shape().raw_dim().ndim(), 3);
-        assert_eq!(format!("{:?}", arr.shape()), format!("{:?}", shape));
+        let err = arr.clip_arrays(&lo, &hi).unwrap_err();
+        assert!(matches!(
+            &err,
+            ArrayError::ShapeMismatch { lhs, rhs } if lhs == &[2, 3] && rhs == &[3, 2]
+        ));
+        assert!(err.to_string().contains("[3, 2]"));
+        assert!(arr.clip_arrays(&hi, &lo).is_err());
     }
 
     #[test]
-    fn array_creation_f64_1d() {
-        let arr = arr![1.1, 2.2, 3.3, 4.4];
-        let ix = Ix::<1>::new([4]);
-        let shape = Shape::new(ix);
+    fn clip_invalid_bounds() {
+        let arr = arr![1, 2, 3];
+        assert!(matches!(
+            arr.clip(5, 1),
+            Err(ArrayError::InvalidArgument(_))
+        ));
+    }
 
-        assert_eq!(arr.shape().raw_dim().size(), 4);
-        assert_eq!(arr.shape().raw_dim().ndim(), 1);
-        assert_eq!(format!("{:?}", arr.shape()), format!("{:?}", shape));
+    #[test]
+    fn clamp_matches_clip() {
+        let arr = arr![[[-3.0, 0.5], [2.0, 9.0]]];
+        let clamped = arr.clamp(0.0, 1.0).unwrap();
+        assert_eq!(clamped.shape().dims(), &[1, 2, 2]);
+        assert_eq!(clamped.data(), arr.clip(0.0, 1.0).unwrap().data());
+        assert_eq!(clamped.data(), &vec![0.0, 0.5, 1.0, 1.0]);
     }
 
     #[test]
-    fn array_creation_f64_2d() {
-        let arr = arr![[1.1, 2.2], [3.3, 4.4], [5.5, 6.6]];
-        let ix = Ix::<2>::new([3, 2]);
-        let shape = Shape::new(ix);
+    fn clip_assign_in_place() {
+        let mut arr = arr![[-5, 0], [5, 10]];
+        arr.clip_assign(0, 7).unwrap();
+        assert_eq!(arr.shape().dims(), &[2, 2]);
+        assert_eq!(arr.data(), &vec![0, 0, 5, 7]);
 
-        assert_eq!(arr.shape().raw_dim().size(), 6);
-        assert_eq!(arr.shape().raw_dim().ndim(), 2);
-        assert_eq!(format!("{:?}", arr.shape()), format!("{:?}", shape));
+        assert!(matches!(
+            arr.clip_assign(3, 2),
+            Err(ArrayError::InvalidArgument(_))
+        ));
+        assert_eq!(arr.data(), &vec![0, 0, 5, 7]);
     }
 
     #[test]
-    fn array_creation_f64_3d() {
-        let arr = arr![
-            [[1.1, 2.2, 3.3], [4.4, 5.5, 6.6]],
-            [[7.7, 8.8, 9.9], [10.0, 11.1, 12.2]]
-        ];
-        let ix = Ix::<3>::new([2, 2, 3]);
-        let shape = Shape::new(ix);
+    fn debug_small_array() {
+        let arr = arr![[1i64, 2, 3], [4, 5, 6]];
+        assert_eq!(
+            format!("{:?}", arr),
+            "Array { shape: [2, 3], dtype: i64, data: [1, 2, 3, 4, 5, 6] }"
+        );
+    }
 
-        assert_eq!(arr.shape().raw_dim().size(), 12);
-        assert_eq!(arr.shape().raw_dim().ndim(), 3);
-        assert_eq!(format!("{:?}", arr.shape()), format!("{:?}", shape));
+    #[test]
+    fn debug_large_array_is_truncated() {
+        let data: Vec<f64> = (0..100).map(|x| x as f64).collect();
+        let arr = Array::new(data, Shape::new(Ix::<2>::new([10, 10]))).unwrap();
+        assert_eq!(
+            format!("{:?}", arr),
+            "Array { shape: [10, 10], dtype: f64, data: [0.0, 1.0, 2.0, ..., 97.0, 98.0, 99.0] }"
+        );
     }
 
     #[test]
@@ -549,6 +5136,155 @@ mod tests {
         assert_eq!(arr.max().axis(2).compute(), vec![3.3, 6.6, 9.9, 12.2]);
     }
 
+    #[test]
+    fn max_with_index_1d() {
+        let arr = arr![42, -17, 256, 3, 256, -8];
+        let (values, indices) = arr.max_with_index(None).unwrap();
+        assert_eq!(values, arr.max().compute());
+        assert_eq!(indices, vec![2]);
+    }
+
+    #[test]
+    fn max_with_index_2d() {
+        let arr = arr![[1, 5, 3], [4, 2, 6], [0, 9, 8]];
+
+        let (values, indices) = arr.max_with_index(None).unwrap();
+        assert_eq!(values, arr.max().compute());
+        assert_eq!(indices, vec![7]);
+
+        let (values, indices) = arr.max_with_index(Some(0)).unwrap();
+        assert_eq!(values, arr.max().axis(0).compute());
+        assert_eq!(indices, vec![1, 2, 2]);
+
+        let (values, indices) = arr.max_with_index(Some(1)).unwrap();
+        assert_eq!(values, arr.max().axis(1).compute());
+        assert_eq!(indices, vec![1, 2, 1]);
+    }
+
+    #[test]
+    fn max_with_index_invalid_axis() {
+        let arr = arr![[1.0, 2.0], [3.0, 4.0]];
+        assert!(matches!(
+            arr.max_with_index(Some(2)),
+            Err(ArrayError::InvalidAxis(_))
+        ));
+    }
+
+    #[test]
+    fn argmax_1d() {
+        let arr = arr![3, 9, 2, 9, 1];
+        assert_eq!(arr.argmax().compute(), vec![1]);
+    }
+
+    #[test]
+    fn argmax_2d_axes() {
+        let arr = arr![[1, 5, 3], [4, 2, 6], [0, 9, 8]];
+        assert_eq!(arr.argmax().compute(), vec![7]);
+        assert_eq!(arr.argmax().axis(0).compute(), vec![1, 2, 2]);
+        assert_eq!(arr.argmax().axis(1).compute(), vec![1, 2, 1]);
+    }
+
+    #[test]
+    fn argmin_1d() {
+        let arr = arr![4, -2, 7, -2];
+        let index = arr.argmin().compute();
+        assert_eq!(index, vec![1]);
+        assert_eq!(arr.data()[index[0]], -2);
+    }
+
+    #[test]
+    fn argmin_2d_axes() {
+        let arr = arr![[3.0, 1.0, 2.0], [0.5, 4.0, 2.0], [5.0, 0.0, 6.0]];
+        assert_eq!(arr.argmin().compute(), vec![7]);
+        assert_eq!(arr.argmin().axis(0).compute(), vec![1, 2, 0]);
+        assert_eq!(arr.argmin().axis(1).compute(), vec![1, 0, 1]);
+    }
+
+    #[test]
+    fn ptp_1d() {
+        let arr = arr![42, -17, 256, 3];
+        assert_eq!(arr.ptp().compute(), vec![273]);
+    }
+
+    #[test]
+    fn ptp_2d_axes() {
+        let arr = arr![[1.0, 5.0, -2.0], [4.0, 0.5, 3.0]];
+        assert_eq!(arr.ptp().compute(), vec![7.0]);
+        assert_eq!(arr.ptp().axis(0).compute(), vec![3.0, 4.5, 5.0]);
+        assert_eq!(arr.ptp().axis(1).compute(), vec![7.0, 3.5]);
+    }
+
+    #[test]
+    fn ptp_3d_axes() {
+        let arr = arr![[[1, 8], [3, 4]], [[5, 2], [7, 9]]];
+        assert_eq!(arr.ptp().compute(), vec![8]);
+        assert_eq!(arr.ptp().axis(0).compute(), vec![4, 6, 4, 5]);
+        assert_eq!(arr.ptp().axis(1).compute(), vec![2, 4, 2, 7]);
+        assert_eq!(arr.ptp().axis(2).compute(), vec![7, 1, 3, 2]);
+        assert!(matches!(
+            arr.ptp_compute(Some(3)),
+            Err(ArrayError::InvalidAxis(_))
+        ));
+    }
+
+    #[test]
+    fn ptp_propagates_nan_in_any_position() {
+        for arr in [
+            arr![1.0, f64::NAN, 3.0],
+            arr![f64::NAN, 1.0, 3.0],
+            arr![1.0, 3.0, f64::NAN],
+        ] {
+            assert!(arr.ptp().compute()[0].is_nan());
+        }
+
+        let arr = arr![[1.0, 4.0], [f64::NAN, 2.0]];
+        let cols = arr.ptp().axis(0).compute();
+        assert!(cols[0].is_nan());
+        assert_eq!(cols[1], 2.0);
+    }
+
+    #[test]
+    fn argmin_errors() {
+        let empty: Array<i32, Ix<1>> = Array::new(vec![], Shape::new(Ix::<1>::new([0]))).unwrap();
+        assert!(matches!(
+            empty.argmin_compute(None),
+            Err(ArrayError::EmptyArray)
+        ));
+
+        let arr = arr![[1, 2], [3, 4]];
+        assert!(matches!(
+            arr.argmin_compute(Some(2)),
+            Err(ArrayError::InvalidAxis(_))
+        ));
+    }
+
+    #[test]
+    fn argmax_ties_first_occurrence() {
+        let arr = arr![[2.0, 7.0, 7.0], [7.0, 2.0, 7.0]];
+        assert_eq!(arr.argmax().compute(), vec![1]);
+        assert_eq!(arr.argmax().axis(0).compute(), vec![1, 0, 0]);
+        assert_eq!(arr.argmax().axis(1).compute(), vec![1, 0]);
+    }
+
+    #[test]
+    fn argmax_argmin_pick_first_nan() {
+        for (arr, index) in [
+            (arr![1.0, f64::NAN, 3.0], 1),
+            (arr![f64::NAN, 1.0, 3.0], 0),
+            (arr![1.0, 3.0, f64::NAN, f64::NAN], 2),
+        ] {
+            assert_eq!(arr.argmax().compute(), vec![index]);
+            assert_eq!(arr.argmin().compute(), vec![index]);
+            let (values, indices) = arr.max_with_index(None).unwrap();
+            assert!(values[0].is_nan());
+            assert_eq!(indices, vec![index]);
+        }
+
+        let arr = arr![[1.0, 5.0], [f64::NAN, 2.0], [3.0, f64::NAN]];
+        assert_eq!(arr.argmax().axis(0).compute(), vec![1, 2]);
+        assert_eq!(arr.argmin().axis(1).compute(), vec![0, 0, 1]);
+    }
+
     #[test]
     fn min_i64_1d() {
         let arr = arr![42, -17, 256, 3, 99, -8];
@@ -693,6 +5429,34 @@ mod tests {
         assert_eq!(arr.data(), &vec![0.0f64; 12]);
     }
 
+    #[test]
+    fn zeros_macro_from_dims_slice() {
+        let dims = vec![2, 3];
+        let arr: Array<i64, Ix<2>> = zeros!(i64, dims: &dims).unwrap();
+        assert_eq!(arr.shape().dims(), &[2, 3]);
+        assert_eq!(arr.dtype(), "int64");
+        assert_eq!(arr.data(), &vec![0i64; 6]);
+
+        let arr: Array<f64, Ix<3>> = zeros!(f64, dims: &[1, 2, 2]).unwrap();
+        assert_eq!(arr.shape().dims(), &[1, 2, 2]);
+        assert_eq!(arr.data(), &vec![0.0; 4]);
+    }
+
+    #[test]
+    fn zeros_macro_from_dims_slice_errors() {
+        let result: Result<Array<i64, Ix<4>>, _> = zeros!(i64, dims: &[1, 2, 3, 4]);
+        assert!(matches!(result, Err(ArrayError::UnimplementedDimension(_))));
+
+        let result: Result<Array<i64, Ix<2>>, _> = zeros!(i64, dims: &[2, 3, 4]);
+        assert!(matches!(
+            result,
+            Err(ArrayError::DimensionMismatch {
+                expected: 2,
+                actual: 3
+            })
+        ));
+    }
+
     #[test]
     fn zeros_method_i64_1d() {
         let mut arr = arr![1, 2, 3, 4];
@@ -858,6 +5622,15 @@ mod tests {
         assert_eq!(arr.data(), &vec![1.0f64; 12]);
     }
 
+    #[test]
+    fn ones_macro_from_dims_slice() {
+        let dims: &[usize] = &[3, 2];
+        let arr: Array<f64, Ix<2>> = ones!(f64, dims: dims).unwrap();
+        assert_eq!(arr.shape().dims(), &[3, 2]);
+        assert_eq!(arr.dtype(), "float64");
+        assert_eq!(arr.data(), &vec![1.0; 6]);
+    }
+
     #[test]
     fn ones_method_i64_1d() {
         let mut arr = arr![1, 2, 3, 4];
@@ -981,6 +5754,424 @@ mod tests {
         assert_vec_approx_eq(arr.mean().axis(1).compute(), expected_mean_axis_1);
     }
 
+    #[test]
+    fn sum_i64_1d() {
+        let arr = arr![1, 2, 3];
+        assert_eq!(arr.sum().compute(), vec![6]);
+        assert_eq!(arr.sum().axis(0).compute(), vec![6]);
+    }
+
+    #[test]
+    fn sum_f64_2d() {
+        let arr = arr![[1.5, 2.5, 3.0], [4.0, 5.0, 6.0]];
+        assert_vec_approx_eq(arr.sum().compute(), vec![22.0]);
+        assert_vec_approx_eq(arr.sum().axis(0).compute(), vec![5.5, 7.5, 9.0]);
+        assert_vec_approx_eq(arr.sum().axis(1).compute(), vec![7.0, 15.0]);
+    }
+
+    #[test]
+    fn sum_i64_3d() {
+        let arr = arr![
+            [[101, 202, 303], [404, 505, 606]],
+            [[-707, -808, -909], [111, 222, 333]]
+        ];
+        assert_eq!(arr.sum().compute(), vec![363]);
+        assert_eq!(
+            arr.sum().axis(0).compute(),
+            vec![-606, -606, -606, 515, 727, 939]
+        );
+        assert_eq!(
+            arr.sum().axis(1).compute(),
+            vec![505, 707, 909, -596, -586, -576]
+        );
+        assert_eq!(arr.sum().axis(2).compute(), vec![606, 1515, -2424, 666]);
+    }
+
+    #[test]
+    fn sum_errors() {
+        let arr = arr![[1, 2], [3, 4]];
+        assert!(matches!(
+            arr.sum_compute(Some(2)),
+            Err(ArrayError::InvalidAxis(_))
+        ));
+
+        let empty: Array<i64, Ix<1>> = Array::new(vec![], Shape::new(Ix::<1>::new([0]))).unwrap();
+        assert!(matches!(empty.sum_compute(None), Err(ArrayError::EmptyArray)));
+    }
+
+    #[test]
+    fn prod_i64_1d() {
+        let arr = arr![1, 2, 3, 4];
+        assert_eq!(arr.prod().compute(), vec![24]);
+        assert_eq!(arr.prod().axis(0).compute(), vec![24]);
+    }
+
+    #[test]
+    fn prod_f64_2d() {
+        let arr = arr![[1.5, 2.0, 3.0], [4.0, 0.5, -1.0]];
+        assert_vec_approx_eq(arr.prod().compute(), vec![-18.0]);
+        assert_vec_approx_eq(arr.prod().axis(0).compute(), vec![6.0, 1.0, -3.0]);
+        assert_vec_approx_eq(arr.prod().axis(1).compute(), vec![9.0, -2.0]);
+    }
+
+    #[test]
+    fn prod_i64_3d() {
+        let arr = arr![[[1, 2], [3, 4]], [[5, 6], [7, 8]]];
+        assert_eq!(arr.prod().compute(), vec![40320]);
+        assert_eq!(arr.prod().axis(0).compute(), vec![5, 12, 21, 32]);
+        assert_eq!(arr.prod().axis(1).compute(), vec![3, 8, 35, 48]);
+        assert_eq!(arr.prod().axis(2).compute(), vec![2, 12, 30, 56]);
+    }
+
+    #[test]
+    fn prod_errors() {
+        let arr = arr![[1, 2], [3, 4]];
+        let err = arr.prod_compute(Some(2)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            arr.max_compute(Some(2)).unwrap_err().to_string()
+        );
+        assert_eq!(
+            err.to_string(),
+            "Invalid axis specified: Axis 2 is out of bounds for array with 2 dimensions"
+        );
+
+        let empty: Array<i64, Ix<1>> = Array::new(vec![], Shape::new(Ix::<1>::new([0]))).unwrap();
+        assert!(matches!(empty.prod_compute(None), Err(ArrayError::EmptyArray)));
+    }
+
+    #[test]
+    fn var_1d() {
+        let arr = arr![1, 2, 3, 4];
+        assert_vec_approx_eq(arr.var().compute(), vec![1.25]);
+        assert_vec_approx_eq(arr.var().ddof(1).compute(), vec![1.667]);
+    }
+
+    #[test]
+    fn var_2d() {
+        let arr = arr![[1.0, 2.0, 4.0], [3.0, 6.0, 8.0]];
+        assert_vec_approx_eq(arr.var().compute(), vec![5.667]);
+        assert_vec_approx_eq(arr.var().axis(0).compute(), vec![1.0, 4.0, 4.0]);
+        assert_vec_approx_eq(arr.var().axis(1).compute(), vec![1.556, 4.222]);
+        assert_vec_approx_eq(arr.var().axis(0).ddof(1).compute(), vec![2.0, 8.0, 8.0]);
+    }
+
+    #[test]
+    fn var_3d() {
+        let arr = arr![[[1, 2], [3, 4]], [[5, 6], [7, 8]]];
+        assert_vec_approx_eq(arr.var().compute(), vec![5.25]);
+        assert_vec_approx_eq(arr.var().axis(0).compute(), vec![4.0; 4]);
+        assert_vec_approx_eq(arr.var().axis(1).compute(), vec![1.0; 4]);
+        assert_vec_approx_eq(arr.var().axis(2).compute(), vec![0.25; 4]);
+    }
+
+    #[test]
+    fn var_ddof_too_large() {
+        let arr = arr![[1.0, 2.0], [3.0, 4.0]];
+        assert!(matches!(
+            arr.var_compute(Some(0), 2),
+            Err(ArrayError::InvalidArgument(_))
+        ));
+        assert!(arr.var_compute(None, 2).is_ok());
+    }
+
+    #[test]
+    fn std_1d() {
+        let arr = arr![1, 2, 3, 4];
+        assert_vec_approx_eq(arr.std().compute(), vec![1.118]);
+        assert_vec_approx_eq(arr.std().ddof(1).compute(), vec![1.291]);
+    }
+
+    #[test]
+    fn std_2d() {
+        let arr = arr![[1.0, 2.0, 4.0], [3.0, 6.0, 8.0]];
+        assert_vec_approx_eq(arr.std().compute(), vec![2.380]);
+        assert_vec_approx_eq(arr.std().ddof(1).compute(), vec![2.608]);
+        assert_vec_approx_eq(arr.std().axis(0).compute(), vec![1.0, 2.0, 2.0]);
+        assert_vec_approx_eq(arr.std().axis(1).compute(), vec![1.247, 2.055]);
+        assert_vec_approx_eq(
+            arr.std().axis(0).ddof(1).compute(),
+            vec![1.414, 2.828, 2.828],
+        );
+    }
+
+    #[test]
+    fn std_3d() {
+        let arr = arr![[[1, 2], [3, 4]], [[5, 6], [7, 8]]];
+        assert_vec_approx_eq(arr.std().compute(), vec![2.291]);
+        assert_vec_approx_eq(arr.std().ddof(1).compute(), vec![2.449]);
+        assert_vec_approx_eq(arr.std().axis(0).compute(), vec![2.0; 4]);
+        assert_vec_approx_eq(arr.std().axis(1).compute(), vec![1.0; 4]);
+        assert_vec_approx_eq(arr.std().axis(2).compute(), vec![0.5; 4]);
+        assert_vec_approx_eq(arr.std().axis(2).ddof(1).compute(), vec![0.707; 4]);
+    }
+
+    #[test]
+    fn std_identical_values_is_zero() {
+        let arr = arr![0.1, 0.1, 0.1];
+        assert_eq!(arr.var().compute(), vec![0.0]);
+        assert_eq!(arr.std().compute(), vec![0.0]);
+        assert_eq!(arr.std().ddof(1).compute(), vec![0.0]);
+    }
+
+    #[test]
+    fn std_propagates_nan() {
+        let arr = arr![1.0, f64::NAN, 3.0];
+        assert!(arr.var().compute()[0].is_nan());
+        assert!(arr.std().compute()[0].is_nan());
+        assert!(arr.reduce_stat(Stat::Std, None).unwrap()[0].is_nan());
+    }
+
+    #[test]
+    fn sum_prod_var_propagate_nan() {
+        let arr = arr![[0.0, f64::NAN, 3.0], [1.0, 2.0, 3.0]];
+        assert!(arr.sum().axis(1).compute()[0].is_nan());
+        assert!(arr.prod().axis(1).compute()[0].is_nan());
+        assert!(arr.var().axis(1).compute()[0].is_nan());
+        assert_eq!(arr.prod().axis(1).compute()[1], 6.0);
+
+        let finite = arr.map(|x| !x.is_nan());
+        assert_eq!(
+            arr.sum().axis(1).where_mask(&finite).compute(),
+            vec![3.0, 6.0]
+        );
+    }
+
+    #[test]
+    fn nan_policy_propagate() {
+        let arr = arr![[1.0, f64::NAN, 3.0], [4.0, 5.0, 6.0]];
+        let max = arr.max().axis(1).nan_policy(NanPolicy::Propagate).compute();
+        assert!(max[0].is_nan());
+        assert_eq!(max[1], 6.0);
+
+        let min = arr.min().compute();
+        assert!(min[0].is_nan());
+
+        let mean = arr.mean().axis(0).compute();
+        assert_eq!(mean[0], 2.5);
+        assert!(mean[1].is_nan());
+        assert_eq!(mean[2], 4.5);
+    }
+
+    #[test]
+    fn nan_policy_ignore() {
+        let arr = arr![[1.0, f64::NAN, 3.0], [4.0, 5.0, f64::NAN]];
+        assert_eq!(arr.max().nan_policy(NanPolicy::Ignore).compute(), vec![5.0]);
+        assert_eq!(
+            arr.min().axis(1).nan_policy(NanPolicy::Ignore).compute(),
+            vec![1.0, 4.0]
+        );
+        assert_vec_approx_eq(
+            arr.mean().axis(1).nan_policy(NanPolicy::Ignore).compute(),
+            vec![2.0, 4.5],
+        );
+
+        let all_nan = arr![f64::NAN, f64::NAN];
+        assert!(all_nan.max().nan_policy(NanPolicy::Ignore).compute()[0].is_nan());
+    }
+
+    #[test]
+    fn nanargmax_skips_nan() {
+        let arr = arr![1.0, 7.0, f64::NAN, 3.0];
+        assert_eq!(arr.nanargmax(None).unwrap(), vec![1]);
+        assert_eq!(arr.nanargmin(None).unwrap(), vec![0]);
+        assert_eq!(arr.argmax().compute(), vec![2]);
+
+        let arr = arr![[f64::NAN, 2.0, 9.0], [4.0, f64::NAN, 1.0]];
+        assert_eq!(arr.nanargmax(None).unwrap(), vec![2]);
+        assert_eq!(arr.nanargmax(Some(1)).unwrap(), vec![2, 0]);
+        assert_eq!(arr.nanargmin(Some(1)).unwrap(), vec![1, 2]);
+        assert_eq!(arr.nanargmin(Some(0)).unwrap(), vec![1, 0, 1]);
+
+        let ints = arr![[3, 8], [5, 1]];
+        assert_eq!(ints.nanargmax(Some(0)).unwrap(), ints.argmax().axis(0).compute());
+    }
+
+    #[test]
+    fn nanargmax_all_nan_lane() {
+        let arr = arr![[f64::NAN, f64::NAN], [1.0, 2.0]];
+        assert_eq!(arr.nanargmax(Some(0)).unwrap(), vec![1, 1]);
+        assert!(matches!(
+            arr.nanargmax(Some(1)),
+            Err(ArrayError::NanEncountered(_))
+        ));
+        assert!(matches!(
+            arr![f64::NAN].nanargmin(None),
+            Err(ArrayError::NanEncountered(_))
+        ));
+        assert!(matches!(arr.nanargmin(Some(2)), Err(ArrayError::InvalidAxis(_))));
+    }
+
+    #[test]
+    fn legacy_compute_matches_builders() {
+        let arr = arr![[1.0, f64::NAN, 3.0], [4.0, 5.0, 6.0]];
+        assert!(arr.max_compute(None).unwrap()[0].is_nan());
+        assert!(arr.min_compute(Some(1)).unwrap()[0].is_nan());
+        assert_eq!(arr.max_compute(Some(1)).unwrap()[1], 6.0);
+        assert_eq!(arr.mean_compute(Some(0)).unwrap()[2], 4.5);
+
+        let data: Vec<f64> = (0..16).map(f64::from).collect();
+        let arr = Array::new(data, Shape::new(Ix::<4>::new([2, 2, 2, 2]))).unwrap();
+        assert_eq!(arr.max_compute(None).unwrap(), arr.max().compute());
+        assert_eq!(arr.max_compute(None).unwrap(), vec![15.0]);
+        assert_eq!(arr.min_compute(Some(3)).unwrap(), arr.min().axis(3).compute());
+        assert_eq!(arr.mean_compute(Some(0)).unwrap(), arr.mean().axis(0).compute());
+    }
+
+    #[test]
+    fn nan_policy_raise() {
+        let arr = arr![[1.0, f64::NAN], [3.0, 4.0]];
+        assert!(matches!(
+            arr.max().nan_policy(NanPolicy::Raise).try_compute(),
+            Err(ArrayError::NanEncountered(_))
+        ));
+        assert!(matches!(
+            arr.mean().axis(1).nan_policy(NanPolicy::Raise).try_compute(),
+            Err(ArrayError::NanEncountered(_))
+        ));
+        assert!(arr
+            .min()
+            .axis(0)
+            .nan_policy(NanPolicy::Raise)
+            .try_compute()
+            .is_err());
+
+        let clean = arr![1.0, 2.0];
+        assert_eq!(
+            clean.max().nan_policy(NanPolicy::Raise).try_compute().unwrap(),
+            vec![2.0]
+        );
+    }
+
+    #[test]
+    fn median_1d() {
+        let arr = arr![7, 1, 3];
+        assert_eq!(arr.median().compute(), vec![3.0]);
+
+        let arr = arr![4.0, 1.0, 3.0, 2.0];
+        assert_eq!(arr.median().compute(), vec![2.5]);
+        assert_eq!(arr.data(), &vec![4.0, 1.0, 3.0, 2.0]);
+    }
+
+    #[test]
+    fn median_2d() {
+        let arr = arr![[1, 9, 3], [8, 2, 6]];
+        assert_eq!(arr.median().compute(), vec![4.5]);
+        assert_eq!(arr.median().axis(0).compute(), vec![4.5, 5.5, 4.5]);
+        assert_eq!(arr.median().axis(1).compute(), vec![3.0, 6.0]);
+    }
+
+    #[test]
+    fn median_3d() {
+        let arr = arr![
+            [[101, 202, 303], [404, 505, 606]],
+            [[-707, -808, -909], [111, 222, 333]]
+        ];
+        assert_eq!(arr.median().compute(), vec![212.0]);
+        assert_eq!(
+            arr.median().axis(0).compute(),
+            vec![-303.0, -303.0, -303.0, 257.5, 363.5, 469.5]
+        );
+        assert_eq!(
+            arr.median().axis(2).compute(),
+            vec![202.0, 505.0, -808.0, 222.0]
+        );
+    }
+
+    #[test]
+    fn median_propagates_nan() {
+        let arr = arr![1.0, f64::NAN, 3.0];
+        assert!(arr.median().compute()[0].is_nan());
+
+        let arr = arr![[1.0, f64::NAN], [3.0, 4.0]];
+        let rows = arr.median().axis(1).compute();
+        assert!(rows[0].is_nan());
+        assert_eq!(rows[1], 3.5);
+        assert!(arr.median().axis(0).compute()[1].is_nan());
+    }
+
+    #[test]
+    fn percentile_1d() {
+        let arr = arr![1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_vec_approx_eq(arr.percentile(90.0).compute(), vec![4.6]);
+        assert_vec_approx_eq(arr.percentile(0.0).compute(), vec![1.0]);
+        assert_vec_approx_eq(arr.percentile(100.0).compute(), vec![5.0]);
+
+        let arr = arr![15, 20, 35, 40, 50, 3];
+        assert_vec_approx_eq(arr.percentile(40.0).compute(), vec![20.0]);
+        assert_vec_approx_eq(arr.percentile(33.0).compute(), vec![18.25]);
+    }
+
+    #[test]
+    fn percentile_2d_axes() {
+        let arr = arr![[10.0, 7.0, 4.0], [3.0, 2.0, 1.0]];
+        assert_vec_approx_eq(arr.percentile(50.0).compute(), vec![3.5]);
+        assert_vec_approx_eq(
+            arr.percentile(50.0).axis(0).compute(),
+            vec![6.5, 4.5, 2.5],
+        );
+        assert_vec_approx_eq(arr.percentile(75.0).axis(1).compute(), vec![8.5, 2.5]);
+    }
+
+    #[test]
+    fn percentile_propagates_nan() {
+        let arr = arr![1.0, f64::NAN, 3.0];
+        for q in [0.0, 25.0, 50.0, 100.0] {
+            assert!(arr.percentile(q).compute()[0].is_nan());
+        }
+
+        let arr = arr![[1.0, 2.0], [f64::NAN, 4.0]];
+        let cols = arr.percentile(50.0).axis(0).compute();
+        assert!(cols[0].is_nan());
+        assert_eq!(cols[1], 3.0);
+    }
+
+    #[test]
+    fn percentile_errors() {
+        let arr = arr![1.0, 2.0, 3.0];
+        assert!(matches!(
+            arr.percentile(100.5).try_compute(),
+            Err(ArrayError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            arr.percentile(-1.0).try_compute(),
+            Err(ArrayError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            arr.percentile(f64::NAN).try_compute(),
+            Err(ArrayError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            arr.percentile(50.0).axis(1).try_compute(),
+            Err(ArrayError::InvalidAxis(_))
+        ));
+    }
+
+    #[test]
+    fn median_errors() {
+        let arr = arr![[1.0, 2.0], [3.0, 4.0]];
+        assert!(matches!(
+            arr.median_compute(Some(2)),
+            Err(ArrayError::InvalidAxis(_))
+        ));
+
+        let empty: Array<f64, Ix<1>> = Array::new(vec![], Shape::new(Ix::<1>::new([0]))).unwrap();
+        assert!(matches!(empty.median_compute(None), Err(ArrayError::EmptyArray)));
+    }
+
+    #[test]
+    fn mean_zero_length_axis() {
+        let arr: Array<f64, Ix<2>> = Array::new(vec![], Shape::new(Ix::<2>::new([3, 0]))).unwrap();
+        assert!(matches!(arr.mean_compute(None), Err(ArrayError::EmptyArray)));
+        assert!(matches!(arr.mean_compute(Some(0)), Err(ArrayError::EmptyArray)));
+        assert!(matches!(arr.mean_compute(Some(1)), Err(ArrayError::EmptyArray)));
+
+        let arr: Array<f64, Ix<3>> =
+            Array::new(vec![], Shape::new(Ix::<3>::new([2, 0, 3]))).unwrap();
+        assert!(matches!(arr.mean_compute(Some(1)), Err(ArrayError::EmptyArray)));
+        assert!(matches!(arr.mean_compute(Some(2)), Err(ArrayError::EmptyArray)));
+    }
+
     #[test]
     fn mean_i64_3d() {
         let arr = arr![
@@ -0,0 +1,137 @@
+use std::fmt::Display;
+
+use num_traits::{One, Zero};
+
+use crate::{Array, ArrayError, Ix};
+
+/// Returns evenly spaced integers in the half-open interval `[start, stop)`, matching NumPy's
+/// `arange`.
+///
+/// Returns `ArrayError::InvalidArgument` if `step` is zero or points away from `stop`, and
+/// `ArrayError::Overflow` if the result would have more than `isize::MAX` elements.
+pub fn arange_i64(start: i64, stop: i64, step: i64) -> Result<Array<i64, Ix<1>>, ArrayError> {
+    let span = stop as i128 - start as i128;
+    if step == 0 || span.signum() * step.signum() as i128 == -1 {
+        return Err(invalid_step(step));
+    }
+
+    let len = (span + step as i128 - step.signum() as i128) / step as i128;
+    if len > isize::MAX as i128 {
+        return Err(too_long(len));
+    }
+
+    // Every value lies in `[start, stop)`, but `i * step` alone may not fit in an `i64`.
+    Ok(Array::from_iter_1d((0..len as usize).map(|i| {
+        (start as i128 + i as i128 * step as i128) as i64
+    })))
+}
+
+/// Returns evenly spaced floats in the half-open interval `[start, stop)`, matching NumPy's
+/// `arange`.
+///
+/// Each value is computed as `start + i * step`, so rounding errors do not accumulate. Returns
+/// `ArrayError::InvalidArgument` if any argument is not finite, or if `step` is zero or points
+/// away from `stop`, and `ArrayError::Overflow` if the result would have more than `isize::MAX`
+/// elements.
+pub fn arange_f64(start: f64, stop: f64, step: f64) -> Result<Array<f64, Ix<1>>, ArrayError> {
+    if !(start.is_finite() && stop.is_finite() && step.is_finite()) {
+        return Err(ArrayError::InvalidArgument(format!(
+            "arange arguments must be finite, got start {}, stop {} and step {}",
+            start, stop, step
+        )));
+    }
+    if step == 0.0 || (stop - start) * step < 0.0 {
+        return Err(invalid_step(step));
+    }
+
+    let len = ((stop - start) / step).ceil();
+    if !len.is_finite() || len > isize::MAX as f64 {
+        return Err(too_long(len));
+    }
+
+    let len = len as usize;
+    Ok(Array::from_iter_1d(
+        (0..len).map(|i| start + i as f64 * step),
+    ))
+}
+
+/// Builds the error for an `arange` whose length does not fit in memory.
+fn too_long(len: impl Display) -> ArrayError {
+    ArrayError::Overflow(format!(
+        "arange would produce {} elements, more than the maximum of {}",
+        len,
+        isize::MAX
+    ))
+}
+
+/// Builds the error for an `arange` step that is zero or never reaches the stop.
+fn invalid_step(step: impl Display) -> ArrayError {
+    ArrayError::InvalidArgument(format!(
+        "arange step {} must be non-zero and point from start towards stop",
+        step
+    ))
+}
+
+/// Returns `num` evenly spaced floats from `start` to `stop`, both included, matching NumPy's
+/// `linspace`.
+///
+/// With `num == 1` the result is `[start]`. Returns `ArrayError::InvalidArgument` if `num` is
+/// zero or `start` or `stop` is not finite.
+pub fn linspace(start: f64, stop: f64, num: usize) -> Result<Array<f64, Ix<1>>, ArrayError> {
+    linspace_endpoint(start, stop, num, true)
+}
+
+/// Returns `num` evenly spaced floats from `start` to `stop` like `linspace`, including `stop`
+/// only when `endpoint` is `true`.
+///
+/// Without the endpoint the samples are spaced `(stop - start) / num` apart, matching NumPy's
+/// `linspace(..., endpoint=False)`.
+pub fn linspace_endpoint(
+    start: f64,
+    stop: f64,
+    num: usize,
+    endpoint: bool,
+) -> Result<Array<f64, Ix<1>>, ArrayError> {
+    if num == 0 {
+        return Err(ArrayError::InvalidArgument(
+            "linspace requires at least one sample".to_string(),
+        ));
+    }
+    if !(start.is_finite() && stop.is_finite()) {
+        return Err(ArrayError::InvalidArgument(format!(
+            "linspace bounds must be finite, got start {} and stop {}",
+            start, stop
+        )));
+    }
+
+    let intervals = if endpoint { num - 1 } else { num };
+    let step = if intervals == 0 {
+        0.0
+    } else {
+        (stop - start) / intervals as f64
+    };
+
+    let mut data: Vec<f64> = (0..num).map(|i| start + i as f64 * step).collect();
+    if endpoint && num > 1 {
+        data[num - 1] = stop;
+    }
+    Ok(Array::from(data))
+}
+
+/// Returns the `n×n` identity matrix, with ones on the main diagonal and zeros elsewhere, like
+/// NumPy's `eye(n)`.
+///
+/// Panics if `n * n` overflows `usize`.
+pub fn eye<T: Zero + One + Copy>(n: usize) -> Array<T, Ix<2>> {
+    eye_rect(n, n)
+}
+
+/// Returns a `rows×cols` matrix with ones on the main diagonal and zeros elsewhere, like NumPy's
+/// `eye(rows, cols)`.
+///
+/// Panics if `rows * cols` overflows `usize`.
+pub fn eye_rect<T: Zero + One + Copy>(rows: usize, cols: usize) -> Array<T, Ix<2>> {
+    let mut eye = Array::from_elem(&[rows, cols], T::zero()).unwrap();
+    eye.fill_diagonal(T::one()).unwrap();
+    eye
+}
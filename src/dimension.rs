@@ -2,7 +2,7 @@
 ///
 /// This trait allows for different representations of dimensions while providing
 /// a common interface for querying array properties.
-pub trait Dimension {
+pub trait Dimension: Clone {
     /// Returns the number of dimensions.
     fn ndim(&self) -> usize;
 
@@ -11,4 +11,22 @@ pub trait Dimension {
 
     /// Returns a slice of the dimensions.
     fn dims(&self) -> &[usize];
+
+    /// Returns a dimension of the same type holding `dims`, or `None` if this type cannot
+    /// represent that many dimensions.
+    ///
+    /// Element-wise arithmetic uses this to build a broadcast shape. The default returns `None`,
+    /// so arrays of a dimension type that does not override it only combine with arrays of the
+    /// same shape.
+    fn with_dims(&self, _dims: &[usize]) -> Option<Self> {
+        None
+    }
+
+    /// Returns the total size (number of elements) of the array, or `None` if the product of
+    /// the dimensions overflows `usize`.
+    fn checked_size(&self) -> Option<usize> {
+        self.dims()
+            .iter()
+            .try_fold(1usize, |size, &dim| size.checked_mul(dim))
+    }
 }
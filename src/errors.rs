@@ -12,6 +12,11 @@ pub enum ArrayError {
     #[error("Dimension mismatch: Expected {expected} elements based on the shape, but the data vector contains {actual} elements")]
     DimensionMismatch { expected: usize, actual: usize },
 
+    /// Raised when two arrays combined element-wise do not have exactly the same shape.
+    /// The error message includes the dimensions of both operands.
+    #[error("Shape mismatch: left operand has shape {lhs:?}, but right operand has shape {rhs:?}")]
+    ShapeMismatch { lhs: Vec<usize>, rhs: Vec<usize> },
+
     /// Raised when an index used to access the array is outside its bounds.
     #[error("Index out of bounds: {0}")]
     IndexOutOfBounds(String),
@@ -31,6 +36,20 @@ pub enum ArrayError {
     /// Signals that the operation requested for an array of a certain dimension is not implemented.
     #[error("Unimplemented dimension: {0}")]
     UnimplementedDimension(String),
+
+    /// Raised when an argument passed to an operation is outside its valid range,
+    /// such as a lower bound that is greater than the upper bound.
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+
+    /// Raised when a computation overflows its numeric type, such as an element count that does
+    /// not fit in `usize` or a checked product that does not fit in the element type.
+    #[error("Overflow: {0}")]
+    Overflow(String),
+
+    /// Raised by reductions configured with `NanPolicy::Raise` when the data contains NaN.
+    #[error("NaN encountered: {0}")]
+    NanEncountered(String),
 }
 
 /// Custom error types for visualization operations.
@@ -1,3 +1,5 @@
+use std::fmt::{self, Debug, Formatter};
+
 use crate::Dimension;
 
 /// Fixed-size index type for multi-dimensional arrays.
@@ -33,4 +35,92 @@ impl<const N: usize> Dimension for Ix<N> {
     fn dims(&self) -> &[usize] {
         &self.dims
     }
+
+    /// Returns an `Ix` holding `dims`, or `None` unless there are exactly `N` of them.
+    fn with_dims(&self, dims: &[usize]) -> Option<Self> {
+        dims.try_into().ok().map(Ix::new)
+    }
+}
+
+/// Number of dimensions an `IxDyn` stores inline before falling back to the heap.
+const INLINE_DIMS: usize = 4;
+
+/// Dynamic-rank index type for multi-dimensional arrays.
+///
+/// Unlike `Ix<N>`, the number of dimensions is only known at runtime, which allows operations such
+/// as `squeeze` whose output rank depends on the data. Shapes of up to four dimensions are stored
+/// inline, so the common low-rank cases never allocate.
+#[derive(Clone)]
+pub struct IxDyn {
+    dims: DimStorage,
+}
+
+/// Backing storage for the dimensions of an `IxDyn`.
+#[derive(Clone)]
+enum DimStorage {
+    Inline {
+        len: usize,
+        dims: [usize; INLINE_DIMS],
+    },
+    Heap(Vec<usize>),
+}
+
+impl IxDyn {
+    /// Creates a new `IxDyn` from a slice of dimensions.
+    pub fn new(dims: &[usize]) -> Self {
+        let dims = if dims.len() <= INLINE_DIMS {
+            let mut inline = [0; INLINE_DIMS];
+            inline[..dims.len()].copy_from_slice(dims);
+            DimStorage::Inline {
+                len: dims.len(),
+                dims: inline,
+            }
+        } else {
+            DimStorage::Heap(dims.to_vec())
+        };
+        IxDyn { dims }
+    }
+}
+
+impl Dimension for IxDyn {
+    /// Returns the number of dimensions represented by this `IxDyn`.
+    fn ndim(&self) -> usize {
+        self.dims().len()
+    }
+
+    /// Calculates the total number of elements in the array described by this `IxDyn`.
+    ///
+    /// This is the product of all dimensions.
+    fn size(&self) -> usize {
+        self.dims().iter().product()
+    }
+
+    /// Returns a slice of the dimensions stored in this `IxDyn`.
+    fn dims(&self) -> &[usize] {
+        match &self.dims {
+            DimStorage::Inline { len, dims } => &dims[..*len],
+            DimStorage::Heap(dims) => dims,
+        }
+    }
+
+    /// Returns an `IxDyn` holding `dims`; any number of dimensions is representable.
+    fn with_dims(&self, dims: &[usize]) -> Option<Self> {
+        Some(IxDyn::new(dims))
+    }
+}
+
+impl PartialEq for IxDyn {
+    /// Compares the dimensions, regardless of whether they are stored inline or on the heap.
+    fn eq(&self, other: &Self) -> bool {
+        self.dims() == other.dims()
+    }
+}
+
+impl Eq for IxDyn {}
+
+impl Debug for IxDyn {
+    /// Formats the `IxDyn` as its dimensions, hiding how they are stored.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IxDyn").field("dims", &self.dims()).finish()
+    }
 }
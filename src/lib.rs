@@ -1,15 +1,48 @@
 #[macro_use]
 pub mod macros;
 pub mod array;
+pub mod creation;
 pub mod dimension;
 pub mod errors;
 pub mod ix;
+pub mod manipulation;
 pub mod operations;
 pub mod shape;
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_util;
+pub mod view;
 pub mod visualization;
 
 pub use array::Array;
+pub use creation::{arange_f64, arange_i64, eye, eye_rect, linspace, linspace_endpoint};
 pub use dimension::Dimension;
 pub use errors::ArrayError;
-pub use ix::Ix;
-pub use shape::Shape;
+pub use ix::{Ix, IxDyn};
+pub use manipulation::{concatenate, stack};
+pub use operations::{max, mean, min, sum};
+pub use shape::{broadcast_shapes, Shape};
+pub use view::ArrayView;
+
+/// A one-dimensional array.
+pub type Array1<T> = Array<T, Ix<1>>;
+
+/// A two-dimensional array.
+///
+/// # Examples
+///
+/// ```
+/// use numru::{arr, Array, Array2, Ix, Shape};
+///
+/// let a: Array2<f64> = arr![[1.0, 2.0], [3.0, 4.0]];
+/// let b = Array2::new(vec![1.0, 2.0, 3.0, 4.0], Shape::new(Ix::<2>::new([2, 2]))).unwrap();
+///
+/// assert_eq!(a.shape().dims(), b.shape().dims());
+/// assert_eq!(a.data(), b.data());
+///
+/// let c: Array<f64, Ix<2>> = b;
+/// assert_eq!(c.data(), &vec![1.0, 2.0, 3.0, 4.0]);
+/// ```
+pub type Array2<T> = Array<T, Ix<2>>;
+
+/// A three-dimensional array.
+pub type Array3<T> = Array<T, Ix<3>>;
@@ -1,8 +1,23 @@
 /// The `arr!` macro is designed to accept arrays of depth 1D, 2D and 3D and flatten them into a
 /// single-dimensional vector. It also tracks and stores the shape (dimensions) of the array, which includes
 /// the number of rows, columns, and further dimensions as needed.
+///
+/// A single `vec!` literal is accepted as well: `arr!(vec![1, 2, 3])` builds a 1D array,
+/// `arr!(vec![vec![1, 2], vec![3, 4]])` a 2D array and a `vec!` nested three levels deep a 3D array.
 #[macro_export]
 macro_rules! arr {
+    (vec![$(vec![$(vec![$($elems:expr),+ $(,)?]),+ $(,)?]),+ $(,)?]) => {
+        $crate::arr![$([$([$($elems),+]),+]),+]
+    };
+
+    (vec![$(vec![$($elems:expr),+ $(,)?]),+ $(,)?]) => {
+        $crate::arr![$([$($elems),+]),+]
+    };
+
+    (vec![$($elem:expr),+ $(,)?]) => {
+        $crate::arr![$($elem),+]
+    };
+
     ($([$([$($elems:expr),+]),+]),+ $(,)?) => {{
         fn flatten_3d<T: Clone>(nested: &[Vec<Vec<T>>]) -> Vec<T> {
             nested.iter().flat_map(|inner| inner.iter().flat_map(|v| v.clone())).collect()
@@ -56,8 +71,26 @@ macro_rules! arr {
 /// The `zeros!` macro creates a multi-dimensional array filled with zeros of the specified data type,
 /// supporting 1D, 2D, and 3D arrays. It generates a flattened vector of zeros and tracks the shape
 /// (dimensions) of the array, which includes the number of rows, columns, and further dimensions as needed.
+///
+/// The dimensions can also be given as a runtime slice with `zeros!(i64, dims: &[2, 3])`. This form
+/// returns a `Result` and the rank is taken from the annotated `Ix<N>` of the result, so a slice of
+/// the wrong length or with more than 3 dimensions produces an `ArrayError` instead of a panic.
 #[macro_export]
 macro_rules! zeros {
+    ($ty:ty, dims: $dims:expr) => {{
+        let dims: &[usize] = $dims;
+        let zero_value: $ty = <$ty as Default>::default();
+
+        if dims.len() > 3 {
+            Err($crate::ArrayError::UnimplementedDimension(format!(
+                "Unsupported number of dimensions (only 1D, 2D, and 3D are supported): {}",
+                dims.len()
+            )))
+        } else {
+            $crate::Array::from_elem(dims, zero_value)
+        }
+    }};
+
     ($ty:ty, $dim:expr) => {{
         let shape = vec![$dim];
         let size = shape.iter().product::<usize>();
@@ -101,8 +134,26 @@ macro_rules! zeros {
 /// The `ones!` macro creates a multi-dimensional array filled with ones of the specified data type,
 /// supporting 1D, 2D, and 3D arrays. It generates a flattened vector of zeros and tracks the shape
 /// (dimensions) of the array, which includes the number of rows, columns, and further dimensions as needed.
+///
+/// Like `zeros!`, the dimensions can also be given as a runtime slice with `ones!(i64, dims: &[2, 3])`,
+/// which returns a `Result` instead of panicking on unsupported shapes.
 #[macro_export]
 macro_rules! ones {
+    ($ty:ty, dims: $dims:expr) => {{
+        use ::num_traits::One;
+        let dims: &[usize] = $dims;
+        let one_value: $ty = <$ty as One>::one();
+
+        if dims.len() > 3 {
+            Err($crate::ArrayError::UnimplementedDimension(format!(
+                "Unsupported number of dimensions (only 1D, 2D, and 3D are supported): {}",
+                dims.len()
+            )))
+        } else {
+            $crate::Array::from_elem(dims, one_value)
+        }
+    }};
+
     ($ty:ty, $dim:expr) => {{
         use ::num_traits::One;
         let shape = vec![$dim];
@@ -145,3 +196,48 @@ macro_rules! ones {
         panic!("Unsupported number of dimensions (only 1D, 2D, and 3D are supported): {}", dimension);
     }};
 }
+
+/// The `assert_array_approx_eq!` macro asserts that two `f64` arrays have the same shape and that
+/// every pair of elements differs by at most a tolerance, which defaults to `1e-8`. Elements that
+/// are both NaN are considered equal.
+///
+/// On failure it panics listing every mismatched element with its flat index, both values and
+/// their difference: `assert_array_approx_eq!(actual, expected, 1e-6)`.
+#[macro_export]
+macro_rules! assert_array_approx_eq {
+    ($actual:expr, $expected:expr $(,)?) => {
+        $crate::assert_array_approx_eq!($actual, $expected, 1e-8)
+    };
+
+    ($actual:expr, $expected:expr, $tol:expr $(,)?) => {{
+        let actual = &$actual;
+        let expected = &$expected;
+        let tol: f64 = $tol;
+
+        assert_eq!(
+            actual.shape().dims(),
+            expected.shape().dims(),
+            "arrays have different shapes"
+        );
+
+        let mismatches: Vec<String> = actual
+            .data()
+            .iter()
+            .zip(expected.data().iter())
+            .enumerate()
+            .map(|(i, (&a, &e)): (usize, (&f64, &f64))| (i, a, e))
+            .filter(|&(_, a, e)| !((a - e).abs() <= tol || (a.is_nan() && e.is_nan())))
+            .map(|(i, a, e)| format!("  [{}]: {} != {} (difference {})", i, a, e, (a - e).abs()))
+            .collect();
+
+        if !mismatches.is_empty() {
+            panic!(
+                "arrays differ in {} of {} elements (tolerance {}):\n{}",
+                mismatches.len(),
+                actual.data().len(),
+                tol,
+                mismatches.join("\n")
+            );
+        }
+    }};
+}
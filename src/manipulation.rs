@@ -0,0 +1,107 @@
+use crate::{Array, ArrayError, Ix, Shape};
+
+/// Joins arrays along an existing axis, matching NumPy's `concatenate`.
+///
+/// All arrays must have the same length on every axis except `axis`. The result keeps the rank of
+/// the inputs, with `axis` as long as the sum of the inputs' lengths along it.
+///
+/// Returns `ArrayError::InvalidArgument` if `arrays` is empty, `ArrayError::InvalidAxis` if
+/// `axis` is out of bounds, and `ArrayError::DimensionMismatch` if the inputs disagree on any
+/// other axis.
+pub fn concatenate<T: Clone, const N: usize>(
+    arrays: &[&Array<T, Ix<N>>],
+    axis: usize,
+) -> Result<Array<T, Ix<N>>, ArrayError> {
+    let first = arrays.first().ok_or_else(|| {
+        ArrayError::InvalidArgument("Cannot concatenate an empty list of arrays".to_string())
+    })?;
+    if axis >= N {
+        return Err(ArrayError::InvalidAxis(format!(
+            "Axis {} is out of bounds for array with {} dimensions",
+            axis, N
+        )));
+    }
+
+    let first_dims = first.shape().dims();
+    let mut dims = [0; N];
+    dims.copy_from_slice(first_dims);
+    dims[axis] = 0;
+    for array in arrays {
+        let array_dims = array.shape().dims();
+        for i in (0..N).filter(|&i| i != axis) {
+            if array_dims[i] != first_dims[i] {
+                return Err(ArrayError::DimensionMismatch {
+                    expected: first_dims[i],
+                    actual: array_dims[i],
+                });
+            }
+        }
+        dims[axis] += array_dims[axis];
+    }
+
+    // Every input contributes a contiguous block of `len * inner` elements per outer index.
+    let outer: usize = dims[..axis].iter().product();
+    let inner: usize = dims[axis + 1..].iter().product();
+    let mut data = Vec::with_capacity(outer * dims[axis] * inner);
+    for o in 0..outer {
+        for array in arrays {
+            let block = array.shape().dims()[axis] * inner;
+            data.extend_from_slice(&array.data()[o * block..(o + 1) * block]);
+        }
+    }
+
+    Array::new(data, Shape::new(Ix::<N>::new(dims)))
+}
+
+/// Joins same-shaped arrays along a new axis inserted at position `axis`, matching NumPy's
+/// `stack`. Unlike `concatenate`, the result has one more dimension than the inputs.
+///
+/// Stable Rust cannot express the output rank as `N + 1`, so it is a separate parameter `M`,
+/// usually inferred from the annotated result type, and must equal `N + 1`. `axis` may range from
+/// `0` (stack in front) to `N` (stack last).
+///
+/// Returns `ArrayError::DimensionMismatch` if `M` is not `N + 1`, `ArrayError::InvalidAxis` if
+/// `axis` is greater than `N`, `ArrayError::InvalidArgument` if `arrays` is empty, and
+/// `ArrayError::ShapeMismatch` if the inputs do not all share the same shape.
+pub fn stack<T: Clone, const N: usize, const M: usize>(
+    arrays: &[&Array<T, Ix<N>>],
+    axis: usize,
+) -> Result<Array<T, Ix<M>>, ArrayError> {
+    if M != N + 1 {
+        return Err(ArrayError::DimensionMismatch {
+            expected: N + 1,
+            actual: M,
+        });
+    }
+    if axis > N {
+        return Err(ArrayError::InvalidAxis(format!(
+            "Axis {} is out of bounds for stacking arrays with {} dimensions",
+            axis, N
+        )));
+    }
+    let first = arrays.first().ok_or_else(|| {
+        ArrayError::InvalidArgument("Cannot stack an empty list of arrays".to_string())
+    })?;
+    let dims = first.shape().dims();
+    if let Some(other) = arrays.iter().find(|a| a.shape().dims() != dims) {
+        return Err(ArrayError::ShapeMismatch {
+            lhs: dims.to_vec(),
+            rhs: other.shape().dims().to_vec(),
+        });
+    }
+
+    let outer: usize = dims[..axis].iter().product();
+    let inner: usize = dims[axis..].iter().product();
+    let mut data = Vec::with_capacity(outer * arrays.len() * inner);
+    for o in 0..outer {
+        for array in arrays {
+            data.extend_from_slice(&array.data()[o * inner..(o + 1) * inner]);
+        }
+    }
+
+    let mut new_dims = [0; M];
+    new_dims[..axis].copy_from_slice(&dims[..axis]);
+    new_dims[axis] = arrays.len();
+    new_dims[axis + 1..].copy_from_slice(&dims[axis..]);
+    Array::new(data, Shape::new(Ix::<M>::new(new_dims)))
+}
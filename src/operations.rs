@@ -1,8 +1,69 @@
 use std::fmt;
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::marker::PhantomData;
+use std::ops::{Add, Mul, Sub};
+use std::str::FromStr;
 
-use crate::{Array, Dimension};
+use num_traits::{One, Zero};
+
+use crate::{Array, ArrayError, Dimension, Ix, IxDyn};
+
+/// Policy describing how reductions treat NaN elements.
+///
+/// Only `MaxBuilder`, `MinBuilder` and `MeanBuilder` accept a policy. The other reductions let
+/// NaN flow through their arithmetic, which matches `NanPolicy::Propagate`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NanPolicy {
+    /// Any NaN in a lane makes the result for that lane NaN.
+    #[default]
+    Propagate,
+    /// NaN elements are skipped; a lane containing only NaN still produces NaN.
+    Ignore,
+    /// Any NaN makes the computation fail with `ArrayError::NanEncountered`.
+    Raise,
+}
+
+/// A reduction selected at runtime, see `Array::reduce_stat`.
+///
+/// Parses from the lowercase statistic name, e.g. `"median".parse::<Stat>()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stat {
+    /// The minimum value.
+    Min,
+    /// The maximum value.
+    Max,
+    /// The arithmetic mean.
+    Mean,
+    /// The sum of the elements.
+    Sum,
+    /// The population standard deviation (`ddof = 0`).
+    Std,
+    /// The population variance (`ddof = 0`).
+    Var,
+    /// The median value.
+    Median,
+}
+
+impl FromStr for Stat {
+    type Err = ArrayError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "min" => Ok(Stat::Min),
+            "max" => Ok(Stat::Max),
+            "mean" => Ok(Stat::Mean),
+            "sum" => Ok(Stat::Sum),
+            "std" => Ok(Stat::Std),
+            "var" => Ok(Stat::Var),
+            "median" => Ok(Stat::Median),
+            _ => Err(ArrayError::InvalidArgument(format!(
+                "Unknown statistic {:?}",
+                s
+            ))),
+        }
+    }
+}
 
 /// A builder for computing the maximum values of an array.
 pub struct MaxBuilder<'a, T, D>
@@ -12,6 +73,9 @@ where
 {
     array: &'a Array<T, D>,
     axis: Option<usize>,
+    nan_policy: NanPolicy,
+    initial: Option<T>,
+    mask: Option<&'a Array<bool, D>>,
 }
 
 impl<'a, T, D> MaxBuilder<'a, T, D>
@@ -21,7 +85,13 @@ where
 {
     /// Creates a new `MaxBuilder` with the given array.
     pub fn new(array: &'a Array<T, D>) -> Self {
-        Self { array, axis: None }
+        Self {
+            array,
+            axis: None,
+            nan_policy: NanPolicy::default(),
+            initial: None,
+            mask: None,
+        }
     }
 
     /// Sets the axis along which to compute the maximum.
@@ -30,9 +100,47 @@ where
         self
     }
 
+    /// Sets how NaN elements are handled, see `NanPolicy`.
+    pub fn nan_policy(mut self, policy: NanPolicy) -> Self {
+        self.nan_policy = policy;
+        self
+    }
+
+    /// Sets a starting value compared against every lane, which is also the result for a lane
+    /// with no elements selected by `where_mask`.
+    pub fn initial(mut self, initial: T) -> Self {
+        self.initial = Some(initial);
+        self
+    }
+
+    /// Restricts the maximum to the elements whose entry in `mask` is `true`.
+    pub fn where_mask(mut self, mask: &'a Array<bool, D>) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
     /// Computes the maximum values based on the current configuration.
     pub fn compute(self) -> Vec<T> {
-        self.array.max_compute(self.axis).unwrap()
+        self.try_compute().unwrap()
+    }
+
+    /// Computes the result as an array shaped like the input with the reduced axis removed, or
+    /// with shape `[1]` when reducing the whole array or a 1D array.
+    pub fn compute_array(self) -> Array<T, IxDyn> {
+        let shape = self.array.reduced_shape(self.axis);
+        Array::new(self.compute(), shape).unwrap()
+    }
+
+    /// Computes the maximum values, returning an error instead of panicking.
+    ///
+    /// A lane left empty by `where_mask` fails with `ArrayError::EmptyArray` unless `initial` is
+    /// given.
+    pub fn try_compute(self) -> Result<Vec<T>, ArrayError> {
+        if self.initial.is_none() && self.mask.is_none() {
+            return self.array.max_compute_with(self.axis, self.nan_policy);
+        }
+        self.array
+            .max_compute_masked(self.axis, self.nan_policy, self.initial, self.mask)
     }
 }
 
@@ -44,6 +152,9 @@ where
 {
     array: &'a Array<T, D>,
     axis: Option<usize>,
+    nan_policy: NanPolicy,
+    initial: Option<T>,
+    mask: Option<&'a Array<bool, D>>,
 }
 
 impl<'a, T, D> MinBuilder<'a, T, D>
@@ -53,7 +164,13 @@ where
 {
     /// Creates a new `MinBuilder` with the given array.
     pub fn new(array: &'a Array<T, D>) -> Self {
-        Self { array, axis: None }
+        Self {
+            array,
+            axis: None,
+            nan_policy: NanPolicy::default(),
+            initial: None,
+            mask: None,
+        }
     }
 
     /// Sets the axis along which to compute the minimum.
@@ -62,9 +179,164 @@ where
         self
     }
 
+    /// Sets how NaN elements are handled, see `NanPolicy`.
+    pub fn nan_policy(mut self, policy: NanPolicy) -> Self {
+        self.nan_policy = policy;
+        self
+    }
+
+    /// Sets a starting value compared against every lane, which is also the result for a lane
+    /// with no elements selected by `where_mask`.
+    pub fn initial(mut self, initial: T) -> Self {
+        self.initial = Some(initial);
+        self
+    }
+
+    /// Restricts the minimum to the elements whose entry in `mask` is `true`.
+    pub fn where_mask(mut self, mask: &'a Array<bool, D>) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
     /// Computes the minimum values based on the current configuration.
     pub fn compute(self) -> Vec<T> {
-        self.array.min_compute(self.axis).unwrap()
+        self.try_compute().unwrap()
+    }
+
+    /// Computes the result as an array shaped like the input with the reduced axis removed, or
+    /// with shape `[1]` when reducing the whole array or a 1D array.
+    pub fn compute_array(self) -> Array<T, IxDyn> {
+        let shape = self.array.reduced_shape(self.axis);
+        Array::new(self.compute(), shape).unwrap()
+    }
+
+    /// Computes the minimum values, returning an error instead of panicking.
+    ///
+    /// A lane left empty by `where_mask` fails with `ArrayError::EmptyArray` unless `initial` is
+    /// given.
+    pub fn try_compute(self) -> Result<Vec<T>, ArrayError> {
+        if self.initial.is_none() && self.mask.is_none() {
+            return self.array.min_compute_with(self.axis, self.nan_policy);
+        }
+        self.array
+            .min_compute_masked(self.axis, self.nan_policy, self.initial, self.mask)
+    }
+}
+
+/// A builder for computing the indices of the maximum values of an array.
+pub struct ArgMaxBuilder<'a, T, D>
+where
+    T: PartialOrd + Copy,
+    D: Dimension,
+{
+    array: &'a Array<T, D>,
+    axis: Option<usize>,
+}
+
+impl<'a, T, D> ArgMaxBuilder<'a, T, D>
+where
+    T: PartialOrd + Copy,
+    D: Dimension,
+{
+    /// Creates a new `ArgMaxBuilder` with the given array.
+    pub fn new(array: &'a Array<T, D>) -> Self {
+        Self { array, axis: None }
+    }
+
+    /// Sets the axis along which to compute the indices of the maximum.
+    pub fn axis(mut self, axis: usize) -> Self {
+        self.axis = Some(axis);
+        self
+    }
+
+    /// Computes the indices of the maximum values based on the current configuration.
+    pub fn compute(self) -> Vec<usize> {
+        self.array.argmax_compute(self.axis).unwrap()
+    }
+
+    /// Computes the result as an array shaped like the input with the reduced axis removed, or
+    /// with shape `[1]` when reducing the whole array or a 1D array.
+    pub fn compute_array(self) -> Array<usize, IxDyn> {
+        let shape = self.array.reduced_shape(self.axis);
+        Array::new(self.compute(), shape).unwrap()
+    }
+}
+
+/// A builder for computing the indices of the minimum values of an array.
+pub struct ArgMinBuilder<'a, T, D>
+where
+    T: PartialOrd + Copy,
+    D: Dimension,
+{
+    array: &'a Array<T, D>,
+    axis: Option<usize>,
+}
+
+impl<'a, T, D> ArgMinBuilder<'a, T, D>
+where
+    T: PartialOrd + Copy,
+    D: Dimension,
+{
+    /// Creates a new `ArgMinBuilder` with the given array.
+    pub fn new(array: &'a Array<T, D>) -> Self {
+        Self { array, axis: None }
+    }
+
+    /// Sets the axis along which to compute the indices of the minimum.
+    pub fn axis(mut self, axis: usize) -> Self {
+        self.axis = Some(axis);
+        self
+    }
+
+    /// Computes the indices of the minimum values based on the current configuration.
+    pub fn compute(self) -> Vec<usize> {
+        self.array.argmin_compute(self.axis).unwrap()
+    }
+
+    /// Computes the result as an array shaped like the input with the reduced axis removed, or
+    /// with shape `[1]` when reducing the whole array or a 1D array.
+    pub fn compute_array(self) -> Array<usize, IxDyn> {
+        let shape = self.array.reduced_shape(self.axis);
+        Array::new(self.compute(), shape).unwrap()
+    }
+}
+
+/// A builder for computing the peak-to-peak range of an array.
+pub struct PtpBuilder<'a, T, D>
+where
+    T: PartialOrd + Copy + Sub<Output = T>,
+    D: Dimension,
+{
+    array: &'a Array<T, D>,
+    axis: Option<usize>,
+}
+
+impl<'a, T, D> PtpBuilder<'a, T, D>
+where
+    T: PartialOrd + Copy + Sub<Output = T>,
+    D: Dimension,
+{
+    /// Creates a new `PtpBuilder` with the given array.
+    pub fn new(array: &'a Array<T, D>) -> Self {
+        Self { array, axis: None }
+    }
+
+    /// Sets the axis along which to compute the peak-to-peak range.
+    pub fn axis(mut self, axis: usize) -> Self {
+        self.axis = Some(axis);
+        self
+    }
+
+    /// Computes the peak-to-peak ranges based on the current configuration.
+    pub fn compute(self) -> Vec<T> {
+        self.array.ptp_compute(self.axis).unwrap()
+    }
+
+    /// Computes the result as an array shaped like the input with the reduced axis removed, or
+    /// with shape `[1]` when reducing the whole array or a 1D array.
+    pub fn compute_array(self) -> Array<T, IxDyn> {
+        let shape = self.array.reduced_shape(self.axis);
+        Array::new(self.compute(), shape).unwrap()
     }
 }
 
@@ -75,7 +347,10 @@ where
     D: Dimension
 {
     array: &'a Array<T,D>,
-    axis: Option<usize>    
+    axis: Option<usize>,
+    nan_policy: NanPolicy,
+    initial: Option<f64>,
+    mask: Option<&'a Array<bool, D>>,
 }
 
 impl<'a, T, D> MeanBuilder<'a, T, D> 
@@ -85,7 +360,13 @@ where
 {
     /// Creates a new `MeanBuilder` with the given array.
     pub fn new(array: &'a Array<T, D>) -> Self {
-        Self { array, axis: None }
+        Self {
+            array,
+            axis: None,
+            nan_policy: NanPolicy::default(),
+            initial: None,
+            mask: None,
+        }
     }
 
     /// Sets the axis along which to compute the minimum.
@@ -94,93 +375,954 @@ where
         self
     }
 
+    /// Sets how NaN elements are handled, see `NanPolicy`.
+    pub fn nan_policy(mut self, policy: NanPolicy) -> Self {
+        self.nan_policy = policy;
+        self
+    }
+
+    /// Sets the result for a lane with no elements selected by `where_mask`. Unlike `sum`, the
+    /// mean does not fold `initial` into lanes that have elements.
+    pub fn initial(mut self, initial: f64) -> Self {
+        self.initial = Some(initial);
+        self
+    }
+
+    /// Restricts the mean to the elements whose entry in `mask` is `true`.
+    pub fn where_mask(mut self, mask: &'a Array<bool, D>) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
     /// Computes the mean values based on the current configuration.
     pub fn compute(self) -> Vec<f64> {
-        self.array.mean_compute(self.axis).unwrap()
+        self.try_compute().unwrap()
+    }
+
+    /// Computes the result as an array shaped like the input with the reduced axis removed, or
+    /// with shape `[1]` when reducing the whole array or a 1D array.
+    pub fn compute_array(self) -> Array<f64, IxDyn> {
+        let shape = self.array.reduced_shape(self.axis);
+        Array::new(self.compute(), shape).unwrap()
+    }
+
+    /// Computes the mean values, returning an error instead of panicking.
+    ///
+    /// A lane left empty by `where_mask` fails with `ArrayError::EmptyArray` unless `initial` is
+    /// given.
+    pub fn try_compute(self) -> Result<Vec<f64>, ArrayError> {
+        if self.initial.is_none() && self.mask.is_none() {
+            return self.array.mean_compute_with(self.axis, self.nan_policy);
+        }
+        self.array
+            .mean_compute_masked(self.axis, self.nan_policy, self.initial, self.mask)
     }
 }
 
-impl<T: PartialOrd + Copy, D: Dimension> Array<T, D> {
-    /// Starts building a computation for the maximum values of this array.
-    pub fn max(&self) -> MaxBuilder<T, D> {
-        MaxBuilder::new(self)
+/// A builder for computing the sum of an array.
+///
+/// Any NaN element makes the sum of its lane NaN. Unlike `MaxBuilder`, the sum cannot be told to
+/// skip NaN; mask those elements out with `where_mask` instead.
+pub struct SumBuilder<'a, T, D>
+where
+    T: Copy + Zero + Add<Output = T>,
+    D: Dimension,
+{
+    array: &'a Array<T, D>,
+    axis: Option<usize>,
+    initial: Option<T>,
+    mask: Option<&'a Array<bool, D>>,
+}
+
+impl<'a, T, D> SumBuilder<'a, T, D>
+where
+    T: Copy + Zero + Add<Output = T>,
+    D: Dimension,
+{
+    /// Creates a new `SumBuilder` with the given array.
+    pub fn new(array: &'a Array<T, D>) -> Self {
+        Self {
+            array,
+            axis: None,
+            initial: None,
+            mask: None,
+        }
     }
 
-    /// Starts building a computation for the minimum values of this array.
-    pub fn min(&self) -> MinBuilder<T, D> {
-        MinBuilder::new(self)
+    /// Sets the axis along which to compute the sum.
+    pub fn axis(mut self, axis: usize) -> Self {
+        self.axis = Some(axis);
+        self
     }
 
-    /// Starts building a computation for the mean values of this array.
-    pub fn mean(&self) -> MeanBuilder<T, D> 
-    where 
-        T: Into<f64>
-    {
-        MeanBuilder::new(self)
+    /// Sets a starting value folded into every lane, which is also the result for a lane with
+    /// no elements selected by `where_mask`.
+    pub fn initial(mut self, initial: T) -> Self {
+        self.initial = Some(initial);
+        self
+    }
+
+    /// Restricts the sum to the elements whose entry in `mask` is `true`.
+    pub fn where_mask(mut self, mask: &'a Array<bool, D>) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
+    /// Computes the sum based on the current configuration.
+    pub fn compute(self) -> Vec<T> {
+        self.try_compute().unwrap()
     }
 
+    /// Computes the sum, returning an error instead of panicking.
+    ///
+    /// With `initial` or `where_mask` set this follows `Array::reduce`, so a lane left empty by
+    /// the mask fails with `ArrayError::EmptyArray` unless `initial` is given.
+    pub fn try_compute(self) -> Result<Vec<T>, ArrayError> {
+        if self.initial.is_none() && self.mask.is_none() {
+            return self.array.sum_compute(self.axis);
+        }
+        self.array
+            .reduce(self.axis, self.initial, self.mask, |a, b| a + b)
+    }
+
+    /// Computes the result as an array shaped like the input with the reduced axis removed, or
+    /// with shape `[1]` when reducing the whole array or a 1D array.
+    pub fn compute_array(self) -> Array<T, IxDyn> {
+        let shape = self.array.reduced_shape(self.axis);
+        Array::new(self.compute(), shape).unwrap()
+    }
 }
 
-impl<T, D> Debug for MaxBuilder<'_, T, D>
+/// A builder for computing the product of an array.
+///
+/// A lane containing NaN has a NaN product, even when the lane also contains a zero.
+pub struct ProdBuilder<'a, T, D>
 where
-    T: PartialOrd + Copy,
+    T: Copy + One + Mul<Output = T>,
     D: Dimension,
 {
-    /// Formats the `MaxBuilder` for debugging.
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        f.debug_struct("MaxBuilder")
-            .field(
-                "array",
-                &format_args!(
-                    "Array<{}, {}>",
-                    std::any::type_name::<T>(),
-                    std::any::type_name::<D>()
-                ),
-            )
-            .field("axis", &self.axis)
-            .finish()
-    }
+    array: &'a Array<T, D>,
+    axis: Option<usize>,
+    initial: Option<T>,
+    mask: Option<&'a Array<bool, D>>,
 }
 
-impl<T, D> Debug for MinBuilder<'_, T, D>
+impl<'a, T, D> ProdBuilder<'a, T, D>
 where
-    T: PartialOrd + Copy,
+    T: Copy + One + Mul<Output = T>,
     D: Dimension,
 {
-    /// Formats the `MinBuilder` for debugging.
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        f.debug_struct("MinBuilder")
-            .field(
-                "array",
-                &format_args!(
-                    "Array<{}, {}>",
-                    std::any::type_name::<T>(),
-                    std::any::type_name::<D>()
-                ),
-            )
-            .field("axis", &self.axis)
-            .finish()
+    /// Creates a new `ProdBuilder` with the given array.
+    pub fn new(array: &'a Array<T, D>) -> Self {
+        Self {
+            array,
+            axis: None,
+            initial: None,
+            mask: None,
+        }
+    }
+
+    /// Sets the axis along which to compute the product.
+    pub fn axis(mut self, axis: usize) -> Self {
+        self.axis = Some(axis);
+        self
+    }
+
+    /// Sets a starting value folded into every lane, which is also the result for a lane with
+    /// no elements selected by `where_mask`.
+    pub fn initial(mut self, initial: T) -> Self {
+        self.initial = Some(initial);
+        self
+    }
+
+    /// Restricts the product to the elements whose entry in `mask` is `true`.
+    pub fn where_mask(mut self, mask: &'a Array<bool, D>) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
+    /// Computes the product based on the current configuration.
+    pub fn compute(self) -> Vec<T> {
+        self.try_compute().unwrap()
+    }
+
+    /// Computes the product, returning an error instead of panicking.
+    ///
+    /// With `initial` or `where_mask` set this follows `Array::reduce`, so a lane left empty by
+    /// the mask fails with `ArrayError::EmptyArray` unless `initial` is given.
+    pub fn try_compute(self) -> Result<Vec<T>, ArrayError> {
+        if self.initial.is_none() && self.mask.is_none() {
+            return self.array.prod_compute(self.axis);
+        }
+        self.array
+            .reduce(self.axis, self.initial, self.mask, |a, b| a * b)
+    }
+
+    /// Computes the result as an array shaped like the input with the reduced axis removed, or
+    /// with shape `[1]` when reducing the whole array or a 1D array.
+    pub fn compute_array(self) -> Array<T, IxDyn> {
+        let shape = self.array.reduced_shape(self.axis);
+        Array::new(self.compute(), shape).unwrap()
     }
 }
 
+/// A builder for computing the variance of an array.
+///
+/// A lane containing NaN has a NaN variance, since the NaN poisons the lane's mean.
+pub struct VarBuilder<'a, T, D>
+where
+    T: Copy + Into<f64>,
+    D: Dimension,
+{
+    array: &'a Array<T, D>,
+    axis: Option<usize>,
+    ddof: usize,
+}
 
-impl<T, D> Debug for MeanBuilder<'_, T, D>
+impl<'a, T, D> VarBuilder<'a, T, D>
 where
-    T: PartialOrd + Copy + Into<f64>,
+    T: Copy + Into<f64>,
     D: Dimension,
 {
-    /// Formats the `MeanBuilder` for debugging.
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        f.debug_struct("MeanBuilder")
-            .field(
-                "array",
-                &format_args!(
-                    "Array<{}, {}>",
-                    std::any::type_name::<T>(),
-                    std::any::type_name::<D>()
-                ),
-            )
-            .field("axis", &self.axis)
-            .finish()
+    /// Creates a new `VarBuilder` with the given array.
+    pub fn new(array: &'a Array<T, D>) -> Self {
+        Self {
+            array,
+            axis: None,
+            ddof: 0,
+        }
+    }
+
+    /// Sets the axis along which to compute the variance.
+    pub fn axis(mut self, axis: usize) -> Self {
+        self.axis = Some(axis);
+        self
+    }
+
+    /// Sets the delta degrees of freedom, so the divisor becomes `N - ddof`.
+    pub fn ddof(mut self, ddof: usize) -> Self {
+        self.ddof = ddof;
+        self
+    }
+
+    /// Computes the variance based on the current configuration.
+    pub fn compute(self) -> Vec<f64> {
+        self.array.var_compute(self.axis, self.ddof).unwrap()
     }
-}
\ No newline at end of file
+
+    /// Computes the result as an array shaped like the input with the reduced axis removed, or
+    /// with shape `[1]` when reducing the whole array or a 1D array.
+    pub fn compute_array(self) -> Array<f64, IxDyn> {
+        let shape = self.array.reduced_shape(self.axis);
+        Array::new(self.compute(), shape).unwrap()
+    }
+}
+
+/// A builder for computing the standard deviation of an array.
+///
+/// The result is the square root of `VarBuilder`'s, so a lane containing NaN yields NaN.
+pub struct StdBuilder<'a, T, D>
+where
+    T: Copy + Into<f64>,
+    D: Dimension,
+{
+    array: &'a Array<T, D>,
+    axis: Option<usize>,
+    ddof: usize,
+}
+
+impl<'a, T, D> StdBuilder<'a, T, D>
+where
+    T: Copy + Into<f64>,
+    D: Dimension,
+{
+    /// Creates a new `StdBuilder` with the given array.
+    pub fn new(array: &'a Array<T, D>) -> Self {
+        Self {
+            array,
+            axis: None,
+            ddof: 0,
+        }
+    }
+
+    /// Sets the axis along which to compute the standard deviation.
+    pub fn axis(mut self, axis: usize) -> Self {
+        self.axis = Some(axis);
+        self
+    }
+
+    /// Sets the delta degrees of freedom, so the variance divisor becomes `N - ddof`.
+    pub fn ddof(mut self, ddof: usize) -> Self {
+        self.ddof = ddof;
+        self
+    }
+
+    /// Computes the standard deviation based on the current configuration.
+    pub fn compute(self) -> Vec<f64> {
+        self.array.std_compute(self.axis, self.ddof).unwrap()
+    }
+
+    /// Computes the result as an array shaped like the input with the reduced axis removed, or
+    /// with shape `[1]` when reducing the whole array or a 1D array.
+    pub fn compute_array(self) -> Array<f64, IxDyn> {
+        let shape = self.array.reduced_shape(self.axis);
+        Array::new(self.compute(), shape).unwrap()
+    }
+}
+
+/// A builder for computing the median of an array.
+pub struct MedianBuilder<'a, T, D>
+where
+    T: Copy + Into<f64>,
+    D: Dimension,
+{
+    array: &'a Array<T, D>,
+    axis: Option<usize>,
+}
+
+impl<'a, T, D> MedianBuilder<'a, T, D>
+where
+    T: Copy + Into<f64>,
+    D: Dimension,
+{
+    /// Creates a new `MedianBuilder` with the given array.
+    pub fn new(array: &'a Array<T, D>) -> Self {
+        Self { array, axis: None }
+    }
+
+    /// Sets the axis along which to compute the median.
+    pub fn axis(mut self, axis: usize) -> Self {
+        self.axis = Some(axis);
+        self
+    }
+
+    /// Computes the median values based on the current configuration.
+    pub fn compute(self) -> Vec<f64> {
+        self.array.median_compute(self.axis).unwrap()
+    }
+
+    /// Computes the result as an array shaped like the input with the reduced axis removed, or
+    /// with shape `[1]` when reducing the whole array or a 1D array.
+    pub fn compute_array(self) -> Array<f64, IxDyn> {
+        let shape = self.array.reduced_shape(self.axis);
+        Array::new(self.compute(), shape).unwrap()
+    }
+}
+
+/// A builder for computing a percentile of an array.
+pub struct PercentileBuilder<'a, T, D>
+where
+    T: Copy + Into<f64>,
+    D: Dimension,
+{
+    array: &'a Array<T, D>,
+    q: f64,
+    axis: Option<usize>,
+}
+
+impl<'a, T, D> PercentileBuilder<'a, T, D>
+where
+    T: Copy + Into<f64>,
+    D: Dimension,
+{
+    /// Creates a new `PercentileBuilder` for the `q`-th percentile of the given array.
+    pub fn new(array: &'a Array<T, D>, q: f64) -> Self {
+        Self {
+            array,
+            q,
+            axis: None,
+        }
+    }
+
+    /// Sets the axis along which to compute the percentile.
+    pub fn axis(mut self, axis: usize) -> Self {
+        self.axis = Some(axis);
+        self
+    }
+
+    /// Computes the percentile values based on the current configuration.
+    pub fn compute(self) -> Vec<f64> {
+        self.try_compute().unwrap()
+    }
+
+    /// Computes the result as an array shaped like the input with the reduced axis removed, or
+    /// with shape `[1]` when reducing the whole array or a 1D array.
+    pub fn compute_array(self) -> Array<f64, IxDyn> {
+        let shape = self.array.reduced_shape(self.axis);
+        Array::new(self.compute(), shape).unwrap()
+    }
+
+    /// Computes the percentile values, returning an error instead of panicking.
+    pub fn try_compute(self) -> Result<Vec<f64>, ArrayError> {
+        self.array.percentile_compute(self.q, self.axis)
+    }
+}
+
+impl<T: PartialOrd + Copy, D: Dimension> Array<T, D> {
+    /// Starts building a computation for the maximum values of this array.
+    pub fn max(&self) -> MaxBuilder<'_, T, D> {
+        MaxBuilder::new(self)
+    }
+
+    /// Starts building a computation for the minimum values of this array.
+    pub fn min(&self) -> MinBuilder<'_, T, D> {
+        MinBuilder::new(self)
+    }
+
+    /// Starts building a computation for the indices of the maximum values of this array.
+    pub fn argmax(&self) -> ArgMaxBuilder<'_, T, D> {
+        ArgMaxBuilder::new(self)
+    }
+
+    /// Starts building a computation for the indices of the minimum values of this array.
+    pub fn argmin(&self) -> ArgMinBuilder<'_, T, D> {
+        ArgMinBuilder::new(self)
+    }
+
+    /// Starts building a computation for the mean values of this array.
+    pub fn mean(&self) -> MeanBuilder<'_, T, D> 
+    where 
+        T: Into<f64>
+    {
+        MeanBuilder::new(self)
+    }
+
+}
+
+impl<T: PartialOrd + Copy + Sub<Output = T>, D: Dimension> Array<T, D> {
+    /// Starts building a computation for the peak-to-peak range of this array.
+    pub fn ptp(&self) -> PtpBuilder<'_, T, D> {
+        PtpBuilder::new(self)
+    }
+}
+
+impl<T: Copy + Zero + Add<Output = T>, D: Dimension> Array<T, D> {
+    /// Starts building a computation for the sum of this array.
+    pub fn sum(&self) -> SumBuilder<'_, T, D> {
+        SumBuilder::new(self)
+    }
+}
+
+impl<T: Copy + One + Mul<Output = T>, D: Dimension> Array<T, D> {
+    /// Starts building a computation for the product of this array.
+    pub fn prod(&self) -> ProdBuilder<'_, T, D> {
+        ProdBuilder::new(self)
+    }
+}
+
+impl<T: Copy + Into<f64>, D: Dimension> Array<T, D> {
+    /// Starts building a computation for the variance of this array.
+    pub fn var(&self) -> VarBuilder<'_, T, D> {
+        VarBuilder::new(self)
+    }
+
+    /// Starts building a computation for the standard deviation of this array.
+    pub fn std(&self) -> StdBuilder<'_, T, D> {
+        StdBuilder::new(self)
+    }
+
+    /// Starts building a computation for the median of this array.
+    pub fn median(&self) -> MedianBuilder<'_, T, D> {
+        MedianBuilder::new(self)
+    }
+
+    /// Starts building a computation for the `q`-th percentile of this array, with `q` in
+    /// `[0, 100]`.
+    pub fn percentile(&self, q: f64) -> PercentileBuilder<'_, T, D> {
+        PercentileBuilder::new(self, q)
+    }
+}
+
+impl<T: PartialOrd + Copy + Into<f64>, D: Dimension> Array<T, D> {
+    /// Computes the statistic `stat` along `axis`, or for the whole array, as `f64` values.
+    ///
+    /// Each variant gives the same result as its dedicated builder with default settings.
+    pub fn reduce_stat(&self, stat: Stat, axis: Option<usize>) -> Result<Vec<f64>, ArrayError> {
+        let to_f64 = |values: Vec<T>| values.into_iter().map(Into::into).collect();
+        match stat {
+            Stat::Min => self.min_compute_with(axis, NanPolicy::default()).map(to_f64),
+            Stat::Max => self.max_compute_with(axis, NanPolicy::default()).map(to_f64),
+            Stat::Mean => self.mean_compute_with(axis, NanPolicy::default()),
+            Stat::Sum => self.map(|x| x.into()).sum_compute(axis),
+            Stat::Std => self.std_compute(axis, 0),
+            Stat::Var => self.var_compute(axis, 0),
+            Stat::Median => self.median_compute(axis),
+        }
+    }
+}
+
+/// Computes the sum of `a` along `axis`, or of the whole array, like `np.sum(a, axis)`.
+///
+/// This is a shorthand for `a.sum().axis(axis).compute()`.
+pub fn sum<T, D>(a: &Array<T, D>, axis: Option<usize>) -> Vec<T>
+where
+    T: Copy + Zero + Add<Output = T>,
+    D: Dimension,
+{
+    match axis {
+        Some(axis) => a.sum().axis(axis).compute(),
+        None => a.sum().compute(),
+    }
+}
+
+/// Computes the mean of `a` along `axis`, or of the whole array, like `np.mean(a, axis)`.
+///
+/// This is a shorthand for `a.mean().axis(axis).compute()`.
+pub fn mean<T, D>(a: &Array<T, D>, axis: Option<usize>) -> Vec<f64>
+where
+    T: PartialOrd + Copy + Into<f64>,
+    D: Dimension,
+{
+    match axis {
+        Some(axis) => a.mean().axis(axis).compute(),
+        None => a.mean().compute(),
+    }
+}
+
+/// Computes the maximum of `a` along `axis`, or of the whole array, like `np.max(a, axis)`.
+///
+/// This is a shorthand for `a.max().axis(axis).compute()`.
+pub fn max<T, D>(a: &Array<T, D>, axis: Option<usize>) -> Vec<T>
+where
+    T: PartialOrd + Copy,
+    D: Dimension,
+{
+    match axis {
+        Some(axis) => a.max().axis(axis).compute(),
+        None => a.max().compute(),
+    }
+}
+
+/// Computes the minimum of `a` along `axis`, or of the whole array, like `np.min(a, axis)`.
+///
+/// This is a shorthand for `a.min().axis(axis).compute()`.
+pub fn min<T, D>(a: &Array<T, D>, axis: Option<usize>) -> Vec<T>
+where
+    T: PartialOrd + Copy,
+    D: Dimension,
+{
+    match axis {
+        Some(axis) => a.min().axis(axis).compute(),
+        None => a.min().compute(),
+    }
+}
+
+/// Computes the covariance of two equal-length series, dividing by `N - ddof`.
+///
+/// Returns `ArrayError::DimensionMismatch` if the lengths differ, `ArrayError::EmptyArray` if the
+/// series are empty, and `ArrayError::InvalidArgument` if `ddof` is not smaller than the length.
+pub fn covariance(
+    a: &Array<f64, Ix<1>>,
+    b: &Array<f64, Ix<1>>,
+    ddof: usize,
+) -> Result<f64, ArrayError> {
+    let (a, b) = (a.data(), b.data());
+    if a.len() != b.len() {
+        return Err(ArrayError::DimensionMismatch {
+            expected: a.len(),
+            actual: b.len(),
+        });
+    }
+    if a.is_empty() {
+        return Err(ArrayError::EmptyArray);
+    }
+    if ddof >= a.len() {
+        return Err(ArrayError::InvalidArgument(format!(
+            "ddof {} must be smaller than the number of elements {}",
+            ddof,
+            a.len()
+        )));
+    }
+
+    let n = a.len() as f64;
+    let mean_a = a.iter().sum::<f64>() / n;
+    let mean_b = b.iter().sum::<f64>() / n;
+    let sum: f64 = a
+        .iter()
+        .zip(b)
+        .map(|(x, y)| (x - mean_a) * (y - mean_b))
+        .sum();
+
+    Ok(sum / (a.len() - ddof) as f64)
+}
+
+/// Computes the Pearson correlation coefficient of two equal-length series.
+///
+/// Returns `ArrayError::DimensionMismatch` if the lengths differ, `ArrayError::EmptyArray` if the
+/// series are empty, and `ArrayError::InvalidArgument` if either series is constant, since the
+/// coefficient is undefined for zero variance. A series counts as constant when every element
+/// lies within rounding error of its mean, so `[0.1, 0.1, 0.1]` is rejected as well.
+pub fn pearson(a: &Array<f64, Ix<1>>, b: &Array<f64, Ix<1>>) -> Result<f64, ArrayError> {
+    let cov = covariance(a, b, 0)?;
+    let var_a = covariance(a, a, 0)?;
+    let var_b = covariance(b, b, 0)?;
+    if is_constant(a.data()) || is_constant(b.data()) {
+        return Err(ArrayError::InvalidArgument(
+            "Pearson correlation is undefined for a constant series".to_string(),
+        ));
+    }
+
+    Ok((cov / (var_a.sqrt() * var_b.sqrt())).clamp(-1.0, 1.0))
+}
+
+/// Returns whether every element of a non-empty series is within a few ulps of the series' mean,
+/// i.e. the series is constant up to the rounding error of computing that mean.
+fn is_constant(values: &[f64]) -> bool {
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+    let scale = values.iter().fold(0.0_f64, |scale, x| scale.max(x.abs()));
+    values
+        .iter()
+        .all(|x| (x - mean).abs() <= 4.0 * f64::EPSILON * scale)
+}
+
+/// Summary statistics produced by `StreamingReducer::finish`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Summary {
+    /// The number of elements reduced.
+    pub count: usize,
+    /// The smallest element.
+    pub min: f64,
+    /// The largest element.
+    pub max: f64,
+    /// The sum of the elements.
+    pub sum: f64,
+    /// The arithmetic mean of the elements.
+    pub mean: f64,
+}
+
+/// Reduces data that arrives in chunks to its min, max, sum and mean without holding it all in
+/// memory.
+///
+/// Each chunk is folded into running state with online updates, so the memory used does not
+/// depend on the amount of data. A NaN element makes the min, max, sum and mean NaN.
+#[derive(Debug, Clone)]
+pub struct StreamingReducer<T> {
+    count: usize,
+    min: f64,
+    max: f64,
+    sum: f64,
+    mean: f64,
+    _element: PhantomData<fn(&T)>,
+}
+
+impl<T: Copy + Into<f64>> StreamingReducer<T> {
+    /// Creates a reducer that has not seen any elements.
+    pub fn new() -> Self {
+        Self {
+            count: 0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+            sum: 0.0,
+            mean: 0.0,
+            _element: PhantomData,
+        }
+    }
+
+    /// Folds the elements of `chunk` into the running statistics.
+    pub fn push(&mut self, chunk: &[T]) {
+        for &x in chunk {
+            let x: f64 = x.into();
+            self.count += 1;
+            if x.is_nan() || x < self.min {
+                self.min = x;
+            }
+            if x.is_nan() || x > self.max {
+                self.max = x;
+            }
+            self.sum += x;
+            self.mean += (x - self.mean) / self.count as f64;
+        }
+    }
+
+    /// Returns the statistics of every element pushed so far.
+    ///
+    /// Returns `ArrayError::EmptyArray` if no elements have been pushed.
+    pub fn finish(&self) -> Result<Summary, ArrayError> {
+        if self.count == 0 {
+            return Err(ArrayError::EmptyArray);
+        }
+
+        Ok(Summary {
+            count: self.count,
+            min: self.min,
+            max: self.max,
+            sum: self.sum,
+            mean: self.mean,
+        })
+    }
+}
+
+impl<T: Copy + Into<f64>> Default for StreamingReducer<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, D> Debug for MaxBuilder<'_, T, D>
+where
+    T: PartialOrd + Copy,
+    D: Dimension,
+{
+    /// Formats the `MaxBuilder` for debugging.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MaxBuilder")
+            .field(
+                "array",
+                &format_args!(
+                    "Array<{}, {}>",
+                    std::any::type_name::<T>(),
+                    std::any::type_name::<D>()
+                ),
+            )
+            .field("axis", &self.axis)
+            .field("nan_policy", &self.nan_policy)
+            .field("has_initial", &self.initial.is_some())
+            .field("masked", &self.mask.is_some())
+            .finish()
+    }
+}
+
+impl<T, D> Debug for MinBuilder<'_, T, D>
+where
+    T: PartialOrd + Copy,
+    D: Dimension,
+{
+    /// Formats the `MinBuilder` for debugging.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MinBuilder")
+            .field(
+                "array",
+                &format_args!(
+                    "Array<{}, {}>",
+                    std::any::type_name::<T>(),
+                    std::any::type_name::<D>()
+                ),
+            )
+            .field("axis", &self.axis)
+            .field("nan_policy", &self.nan_policy)
+            .field("has_initial", &self.initial.is_some())
+            .field("masked", &self.mask.is_some())
+            .finish()
+    }
+}
+
+
+impl<T, D> Debug for MeanBuilder<'_, T, D>
+where
+    T: PartialOrd + Copy + Into<f64>,
+    D: Dimension,
+{
+    /// Formats the `MeanBuilder` for debugging.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MeanBuilder")
+            .field(
+                "array",
+                &format_args!(
+                    "Array<{}, {}>",
+                    std::any::type_name::<T>(),
+                    std::any::type_name::<D>()
+                ),
+            )
+            .field("axis", &self.axis)
+            .field("nan_policy", &self.nan_policy)
+            .field("has_initial", &self.initial.is_some())
+            .field("masked", &self.mask.is_some())
+            .finish()
+    }
+}
+
+impl<T, D> Debug for SumBuilder<'_, T, D>
+where
+    T: Copy + Zero + Add<Output = T>,
+    D: Dimension,
+{
+    /// Formats the `SumBuilder` for debugging.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SumBuilder")
+            .field(
+                "array",
+                &format_args!(
+                    "Array<{}, {}>",
+                    std::any::type_name::<T>(),
+                    std::any::type_name::<D>()
+                ),
+            )
+            .field("axis", &self.axis)
+            .field("has_initial", &self.initial.is_some())
+            .field("masked", &self.mask.is_some())
+            .finish()
+    }
+}
+
+impl<T, D> Debug for ProdBuilder<'_, T, D>
+where
+    T: Copy + One + Mul<Output = T>,
+    D: Dimension,
+{
+    /// Formats the `ProdBuilder` for debugging.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProdBuilder")
+            .field(
+                "array",
+                &format_args!(
+                    "Array<{}, {}>",
+                    std::any::type_name::<T>(),
+                    std::any::type_name::<D>()
+                ),
+            )
+            .field("axis", &self.axis)
+            .field("has_initial", &self.initial.is_some())
+            .field("masked", &self.mask.is_some())
+            .finish()
+    }
+}
+
+impl<T, D> Debug for VarBuilder<'_, T, D>
+where
+    T: Copy + Into<f64>,
+    D: Dimension,
+{
+    /// Formats the `VarBuilder` for debugging.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VarBuilder")
+            .field(
+                "array",
+                &format_args!(
+                    "Array<{}, {}>",
+                    std::any::type_name::<T>(),
+                    std::any::type_name::<D>()
+                ),
+            )
+            .field("axis", &self.axis)
+            .field("ddof", &self.ddof)
+            .finish()
+    }
+}
+
+impl<T, D> Debug for StdBuilder<'_, T, D>
+where
+    T: Copy + Into<f64>,
+    D: Dimension,
+{
+    /// Formats the `StdBuilder` for debugging.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StdBuilder")
+            .field(
+                "array",
+                &format_args!(
+                    "Array<{}, {}>",
+                    std::any::type_name::<T>(),
+                    std::any::type_name::<D>()
+                ),
+            )
+            .field("axis", &self.axis)
+            .field("ddof", &self.ddof)
+            .finish()
+    }
+}
+
+impl<T, D> Debug for MedianBuilder<'_, T, D>
+where
+    T: Copy + Into<f64>,
+    D: Dimension,
+{
+    /// Formats the `MedianBuilder` for debugging.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MedianBuilder")
+            .field(
+                "array",
+                &format_args!(
+                    "Array<{}, {}>",
+                    std::any::type_name::<T>(),
+                    std::any::type_name::<D>()
+                ),
+            )
+            .field("axis", &self.axis)
+            .finish()
+    }
+}
+
+impl<T, D> Debug for PercentileBuilder<'_, T, D>
+where
+    T: Copy + Into<f64>,
+    D: Dimension,
+{
+    /// Formats the `PercentileBuilder` for debugging.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PercentileBuilder")
+            .field(
+                "array",
+                &format_args!(
+                    "Array<{}, {}>",
+                    std::any::type_name::<T>(),
+                    std::any::type_name::<D>()
+                ),
+            )
+            .field("q", &self.q)
+            .field("axis", &self.axis)
+            .finish()
+    }
+}
+
+impl<T, D> Debug for ArgMaxBuilder<'_, T, D>
+where
+    T: PartialOrd + Copy,
+    D: Dimension,
+{
+    /// Formats the `ArgMaxBuilder` for debugging.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArgMaxBuilder")
+            .field(
+                "array",
+                &format_args!(
+                    "Array<{}, {}>",
+                    std::any::type_name::<T>(),
+                    std::any::type_name::<D>()
+                ),
+            )
+            .field("axis", &self.axis)
+            .finish()
+    }
+}
+
+impl<T, D> Debug for ArgMinBuilder<'_, T, D>
+where
+    T: PartialOrd + Copy,
+    D: Dimension,
+{
+    /// Formats the `ArgMinBuilder` for debugging.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArgMinBuilder")
+            .field(
+                "array",
+                &format_args!(
+                    "Array<{}, {}>",
+                    std::any::type_name::<T>(),
+                    std::any::type_name::<D>()
+                ),
+            )
+            .field("axis", &self.axis)
+            .finish()
+    }
+}
+
+impl<T, D> Debug for PtpBuilder<'_, T, D>
+where
+    T: PartialOrd + Copy + Sub<Output = T>,
+    D: Dimension,
+{
+    /// Formats the `PtpBuilder` for debugging.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PtpBuilder")
+            .field(
+                "array",
+                &format_args!(
+                    "Array<{}, {}>",
+                    std::any::type_name::<T>(),
+                    std::any::type_name::<D>()
+                ),
+            )
+            .field("axis", &self.axis)
+            .finish()
+    }
+}
@@ -1,6 +1,6 @@
 use std::fmt::{Debug, Formatter, Result};
 
-use crate::Dimension;
+use crate::{ArrayError, Dimension};
 
 /// Represents the shape of an array or matrix, encapsulating the dimensions.
 ///
@@ -57,3 +57,29 @@ where
         Shape { dims: dimension }
     }
 }
+
+/// Computes the shape that two shapes broadcast to, following NumPy's broadcasting rules.
+///
+/// The shapes are aligned on their trailing axes, with the shorter one padded with leading ones.
+/// Two aligned lengths are compatible when they are equal or either is one, and the result takes
+/// the larger. Returns `ArrayError::DimensionMismatch` with the first incompatible pair of
+/// lengths, e.g. for `[3]` and `[4]`.
+pub fn broadcast_shapes(a: &[usize], b: &[usize]) -> std::result::Result<Vec<usize>, ArrayError> {
+    let ndim = a.len().max(b.len());
+    let aligned = |dims: &[usize], axis: usize| {
+        (axis + dims.len())
+            .checked_sub(ndim)
+            .map_or(1, |axis| dims[axis])
+    };
+
+    (0..ndim)
+        .map(|axis| match (aligned(a, axis), aligned(b, axis)) {
+            (x, y) if x == y || y == 1 => Ok(x),
+            (1, y) => Ok(y),
+            (x, y) => Err(ArrayError::DimensionMismatch {
+                expected: x,
+                actual: y,
+            }),
+        })
+        .collect()
+}
@@ -0,0 +1,57 @@
+//! Helpers for exercising the manipulation APIs in tests.
+//!
+//! The module is compiled for the crate's own tests and, for downstream property tests, behind
+//! the `test-util` feature. Generators are deterministic so failures can be reproduced.
+
+use std::fmt::Debug;
+
+use crate::{Array, Ix, Shape};
+
+/// Asserts that reshaping `a` to 1D and back, and transposing it twice, both reproduce `a`.
+///
+/// Panics with the offending array and intermediate result if either roundtrip fails.
+pub fn assert_roundtrip<T>(a: &Array<T, Ix<2>>)
+where
+    T: Copy + PartialEq + Debug,
+{
+    let dims = [a.shape().dims()[0], a.shape().dims()[1]];
+
+    let reshaped = a
+        .reshape([a.data().len()])
+        .and_then(|flat| flat.reshape(dims))
+        .expect("reshape roundtrip must preserve the element count");
+    assert_eq!(&reshaped, a, "reshape roundtrip changed the array");
+
+    let transposed = a.transpose().transpose();
+    assert_eq!(&transposed, a, "transpose roundtrip changed the array");
+}
+
+/// Returns an array of the given shape holding `0, 1, 2, ...` in row-major order.
+pub fn sequential_array<const N: usize>(dims: [usize; N]) -> Array<i64, Ix<N>> {
+    let size = dims.iter().product::<usize>() as i64;
+    Array::new((0..size).collect(), Shape::new(Ix::<N>::new(dims))).unwrap()
+}
+
+/// Returns an array of the given shape filled with pseudo-random values in `[-1, 1)`.
+///
+/// The same `seed` always yields the same values.
+pub fn random_array<const N: usize>(dims: [usize; N], seed: u64) -> Array<f64, Ix<N>> {
+    let size = dims.iter().product();
+    let mut state = seed;
+    let data = (0..size)
+        .map(|_| {
+            // 64-bit linear congruential generator (Knuth's MMIX constants).
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 11) as f64 / (1u64 << 52) as f64 - 1.0
+        })
+        .collect();
+
+    Array::new(data, Shape::new(Ix::<N>::new(dims))).unwrap()
+}
+
+/// Returns every 2D shape with both dimensions in `1..=max`.
+pub fn shapes_2d(max: usize) -> impl Iterator<Item = [usize; 2]> {
+    (1..=max).flat_map(move |rows| (1..=max).map(move |cols| [rows, cols]))
+}
@@ -0,0 +1,349 @@
+use std::ops::{Add, Mul};
+
+use num_traits::Zero;
+
+use crate::{Array, ArrayError, Dimension, Ix, Shape};
+
+/// A borrowed, strided view into the data of an `Array`.
+///
+/// A view describes how to walk a slice of data through a shape, a start offset and one stride
+/// per axis. Strides may be negative, which allows reversed or transposed access to an array
+/// without copying its elements.
+pub struct ArrayView<'a, T, D: Dimension> {
+    data: &'a [T],
+    offset: usize,
+    shape: Shape<D>,
+    strides: Vec<isize>,
+}
+
+impl<'a, T, D: Dimension> ArrayView<'a, T, D> {
+    /// Constructs a new `ArrayView` over `data`, starting at `offset` and using `strides`.
+    ///
+    /// The caller must ensure that every index reachable through the shape and strides lies
+    /// within `data`.
+    pub(crate) fn new(data: &'a [T], offset: usize, shape: Shape<D>, strides: Vec<isize>) -> Self {
+        ArrayView {
+            data,
+            offset,
+            shape,
+            strides,
+        }
+    }
+
+    /// Returns a reference to the shape of the view.
+    pub fn shape(&self) -> &Shape<D> {
+        &self.shape
+    }
+
+    /// Returns the stride of every axis, in elements.
+    pub fn strides(&self) -> &[isize] {
+        &self.strides
+    }
+
+    /// Returns an iterator over the elements of the view in logical row-major order.
+    pub fn iter(&self) -> ViewIter<'_, 'a, T, D> {
+        ViewIter {
+            view: self,
+            index: 0,
+            len: self.shape.size(),
+        }
+    }
+
+    /// Returns a reference to the element at the multi-dimensional `index`, or `None` if the
+    /// index does not match the shape of the view.
+    pub fn get(&self, index: &[usize]) -> Option<&'a T> {
+        let dims = self.shape.dims();
+        if index.len() != dims.len() || index.iter().zip(dims).any(|(&i, &d)| i >= d) {
+            return None;
+        }
+
+        let offset = index
+            .iter()
+            .zip(&self.strides)
+            .fold(self.offset as isize, |offset, (&i, &stride)| {
+                offset + i as isize * stride
+            });
+        Some(&self.data[offset as usize])
+    }
+
+    /// Copies the elements of the view, in logical row-major order, into a new contiguous array
+    /// with the same shape.
+    pub fn to_owned(&self) -> Array<T, D>
+    where
+        T: Clone,
+    {
+        let data = self.iter().cloned().collect();
+        Array::new(data, self.shape.clone()).unwrap()
+    }
+
+    /// Returns whether the view walks its data in contiguous row-major (C) order.
+    ///
+    /// Axes of length one never move through the data, so their strides are ignored.
+    pub fn is_standard_layout(&self) -> bool {
+        let mut expected = 1isize;
+        for (&dim, &stride) in self.shape.dims().iter().zip(&self.strides).rev() {
+            if dim > 1 && stride != expected {
+                return false;
+            }
+            expected *= dim as isize;
+        }
+        true
+    }
+
+    /// Returns an owned array holding the elements of the view in contiguous row-major order,
+    /// matching NumPy's `ascontiguousarray`.
+    ///
+    /// A view that is already in standard layout is copied as a single slice; any other view is
+    /// gathered element by element through its strides.
+    pub fn as_standard_layout(&self) -> Array<T, D>
+    where
+        T: Clone,
+    {
+        if !self.is_standard_layout() {
+            return self.to_owned();
+        }
+
+        let data = self.data[self.offset..self.offset + self.shape.size()].to_vec();
+        Array::new(data, self.shape.clone()).unwrap()
+    }
+
+    /// Translates the `n`-th element in logical row-major order into an offset into `data`.
+    fn offset_of(&self, mut n: usize) -> usize {
+        let mut offset = self.offset as isize;
+        for (&dim, &stride) in self.shape.dims().iter().zip(&self.strides).rev() {
+            offset += (n % dim) as isize * stride;
+            n /= dim;
+        }
+        offset as usize
+    }
+}
+
+impl<T: Copy, D: Dimension> ArrayView<'_, T, D> {
+    /// Reduces every lane along `axis` with `reduce`, walking the data through the strides of the
+    /// view. With no axis, the whole view forms a single lane in logical row-major order.
+    ///
+    /// Lanes are ordered row-major over the remaining axes, matching the axis reductions of
+    /// `Array`. Returns `ArrayError::EmptyArray` for a view without elements and
+    /// `ArrayError::InvalidAxis` if `axis` is out of bounds.
+    fn reduce_lanes<R>(
+        &self,
+        axis: Option<usize>,
+        reduce: impl Fn(&mut dyn Iterator<Item = T>) -> R,
+    ) -> Result<Vec<R>, ArrayError> {
+        if self.shape.size() == 0 {
+            return Err(ArrayError::EmptyArray);
+        }
+
+        let dims = self.shape.dims();
+        let axis = match axis {
+            None => return Ok(vec![reduce(&mut self.iter().copied())]),
+            Some(axis) if axis >= dims.len() => {
+                return Err(ArrayError::InvalidAxis(format!(
+                    "Axis {} is out of bounds for array with {} dimensions",
+                    axis,
+                    dims.len()
+                )))
+            }
+            Some(axis) => axis,
+        };
+
+        let (len, stride) = (dims[axis], self.strides[axis]);
+        let outer: Vec<(usize, isize)> = dims
+            .iter()
+            .zip(&self.strides)
+            .enumerate()
+            .filter(|&(i, _)| i != axis)
+            .map(|(_, (&dim, &stride))| (dim, stride))
+            .collect();
+        let lanes: usize = outer.iter().map(|&(dim, _)| dim).product();
+
+        Ok((0..lanes)
+            .map(|mut n| {
+                let mut start = self.offset as isize;
+                for &(dim, stride) in outer.iter().rev() {
+                    start += (n % dim) as isize * stride;
+                    n /= dim;
+                }
+                reduce(&mut (0..len).map(|i| self.data[(start + i as isize * stride) as usize]))
+            })
+            .collect())
+    }
+
+    /// Computes the sum along `axis`, or of the whole view, like `Array::sum_compute`.
+    pub fn sum(&self, axis: Option<usize>) -> Result<Vec<T>, ArrayError>
+    where
+        T: Zero + Add<Output = T>,
+    {
+        self.reduce_lanes(axis, |values| values.fold(T::zero(), |acc, x| acc + x))
+    }
+
+    /// Computes the maximum along `axis`, or of the whole view, like `Array::max_compute`.
+    ///
+    /// A lane containing NaN yields its first NaN.
+    pub fn max(&self, axis: Option<usize>) -> Result<Vec<T>, ArrayError>
+    where
+        T: PartialOrd,
+    {
+        self.reduce_lanes(axis, |values| extreme(values, |x, best| x > best))
+    }
+
+    /// Computes the minimum along `axis`, or of the whole view, like `Array::min_compute`.
+    ///
+    /// A lane containing NaN yields its first NaN.
+    pub fn min(&self, axis: Option<usize>) -> Result<Vec<T>, ArrayError>
+    where
+        T: PartialOrd,
+    {
+        self.reduce_lanes(axis, |values| extreme(values, |x, best| x < best))
+    }
+
+    /// Computes the mean along `axis`, or of the whole view, like `Array::mean_compute`.
+    pub fn mean(&self, axis: Option<usize>) -> Result<Vec<f64>, ArrayError>
+    where
+        T: Into<f64>,
+    {
+        self.reduce_lanes(axis, |values| {
+            let (sum, count) = values.fold((0.0, 0), |(sum, count), x| {
+                (sum + Into::<f64>::into(x), count + 1)
+            });
+            sum / count as f64
+        })
+    }
+}
+
+/// Returns the element of the non-empty `values` that `is_better` prefers over all others, or
+/// the first NaN (an element that does not compare with itself) if there is one.
+fn extreme<T: PartialOrd + Copy>(
+    values: &mut dyn Iterator<Item = T>,
+    is_better: impl Fn(&T, &T) -> bool,
+) -> T {
+    let is_nan = |x: &T| x.partial_cmp(x).is_none();
+    values
+        .reduce(|best, x| {
+            if !is_nan(&best) && (is_nan(&x) || is_better(&x, &best)) {
+                x
+            } else {
+                best
+            }
+        })
+        .unwrap()
+}
+
+impl<T, D: Dimension> Clone for ArrayView<'_, T, D> {
+    fn clone(&self) -> Self {
+        ArrayView {
+            data: self.data,
+            offset: self.offset,
+            shape: self.shape.clone(),
+            strides: self.strides.clone(),
+        }
+    }
+}
+
+impl<T> ArrayView<'_, T, Ix<2>>
+where
+    T: Copy + Zero + Add<Output = T> + Mul<Output = T>,
+{
+    /// Computes the matrix product of this view with `rhs`, respecting the strides of both
+    /// operands so that transposed views never have to be materialized.
+    ///
+    /// `self` must be `[m, k]` and `rhs` `[k, n]`, producing an owned `[m, n]` array. Returns
+    /// `ArrayError::DimensionMismatch` if the inner dimensions differ, like `Array::matmul_f64`,
+    /// with the column count of `self` as `expected` and the row count of `rhs` as `actual`.
+    pub fn matmul<R: AsView<T, Ix<2>>>(&self, rhs: &R) -> Result<Array<T, Ix<2>>, ArrayError> {
+        let rhs = rhs.as_view();
+        let (m, k) = (self.shape.dims()[0], self.shape.dims()[1]);
+        let (k2, n) = (rhs.shape.dims()[0], rhs.shape.dims()[1]);
+        if k != k2 {
+            return Err(ArrayError::DimensionMismatch {
+                expected: k,
+                actual: k2,
+            });
+        }
+
+        let mut data = vec![T::zero(); m * n];
+        matmul_kernel(
+            [m, k, n],
+            |i, p| self.data[self.offset_at(i, p)],
+            |p, j| rhs.data[rhs.offset_at(p, j)],
+            &mut data,
+        );
+
+        Array::new(data, Shape::new(Ix::<2>::new([m, n])))
+    }
+
+    /// Translates a row and column into an offset into `data`.
+    fn offset_at(&self, row: usize, col: usize) -> usize {
+        (self.offset as isize + row as isize * self.strides[0] + col as isize * self.strides[1])
+            as usize
+    }
+}
+
+/// Accumulates the product of an `[m, k]` and a `[k, n]` matrix into the row-major `out`.
+///
+/// The operands are read through `lhs(i, p)` and `rhs(p, j)` so that the loop order stays
+/// independent of their layout; a blocked or parallel kernel can replace this one without
+/// touching `matmul`.
+fn matmul_kernel<T>(
+    [m, k, n]: [usize; 3],
+    lhs: impl Fn(usize, usize) -> T,
+    rhs: impl Fn(usize, usize) -> T,
+    out: &mut [T],
+) where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+{
+    for i in 0..m {
+        for p in 0..k {
+            let a = lhs(i, p);
+            for j in 0..n {
+                out[i * n + j] = out[i * n + j] + a * rhs(p, j);
+            }
+        }
+    }
+}
+
+/// Types that can be borrowed as an `ArrayView`, allowing operations to accept owned arrays and
+/// views interchangeably.
+pub trait AsView<T, D: Dimension> {
+    /// Returns a view of the data.
+    fn as_view(&self) -> ArrayView<'_, T, D>;
+}
+
+impl<T, D: Dimension> AsView<T, D> for Array<T, D> {
+    fn as_view(&self) -> ArrayView<'_, T, D> {
+        self.view()
+    }
+}
+
+impl<T, D: Dimension> AsView<T, D> for ArrayView<'_, T, D> {
+    fn as_view(&self) -> ArrayView<'_, T, D> {
+        self.clone()
+    }
+}
+
+/// Iterator over the elements of an `ArrayView` in logical row-major order.
+pub struct ViewIter<'v, 'a, T, D: Dimension> {
+    view: &'v ArrayView<'a, T, D>,
+    index: usize,
+    len: usize,
+}
+
+impl<'a, T, D: Dimension> Iterator for ViewIter<'_, 'a, T, D> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+        let item = &self.view.data[self.view.offset_of(self.index)];
+        self.index += 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T, D: Dimension> ExactSizeIterator for ViewIter<'_, '_, T, D> {}
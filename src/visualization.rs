@@ -35,7 +35,7 @@ pub struct VisualizeBuilder<'a, T, D: Dimension> {
 
 impl<T: Display + FormatValue, D: Dimension> Array<T, D> {
     /// Starts the visualization process with default settings.
-    pub fn visualize(&self) -> VisualizeBuilder<T, D> {
+    pub fn visualize(&self) -> VisualizeBuilder<'_, T, D> {
         VisualizeBuilder {
             array: self,
             decimal_points: 1,
@@ -50,87 +50,119 @@ impl<'a, T: Display + FormatValue, D: Dimension> VisualizeBuilder<'a, T, D> {
         self
     }
 
-    /// Executes the visualization with the configured settings.
+    /// Executes the visualization with the configured settings, printing the output of `render`.
     pub fn execute(&self) {
+        print!("{}", self.render());
+    }
+
+    /// Renders the array with the configured settings into a string.
+    ///
+    /// A level of the array with no elements below it is rendered as `[]`, so an array with a
+    /// zero-length axis such as `[2, 0, 3]` shows its empty structure instead of nothing.
+    pub fn render(&self) -> String {
         let dims = self.array.shape().dims();
         let ndim = dims.len();
+        let mut out = String::new();
 
         if ndim == 1 {
             let rows = dims[0];
-            print!("[");
+            out.push('[');
             for i in 0..rows {
                 let value = &self.array.data()[i];
                 let value_str = value.format_value(self.decimal_points);
-                print!("{}", value_str.unwrap());
+                out.push_str(&value_str.unwrap());
                 if i < rows - 1 {
-                    print!(", ");
+                    out.push_str(", ");
                 }
             }
-            println!("]");
+            out.push_str("]\n");
         } else if ndim == 2 {
             let rows = dims[0];
             let cols = dims[1];
 
+            if rows == 0 {
+                return "[]\n".to_string();
+            }
+
             let mut column_widths = vec![0; cols];
             for i in 0..rows {
-                for j in 0..cols {
+                for (j, column_width) in column_widths.iter_mut().enumerate() {
                     let value = &self.array.data()[i * cols + j];
                     let width = value.format_value(self.decimal_points).unwrap().len();
-                    column_widths[j] = column_widths[j].max(width);
+                    *column_width = (*column_width).max(width);
                 }
             }
 
-            println!("[");
+            out.push_str("[\n");
             for i in 0..rows {
-                print!("   [");
-                for j in 0..cols {
+                out.push_str("   [");
+                for (j, column_width) in column_widths.iter().enumerate() {
                     let value = &self.array.data()[i * cols + j];
                     let value_str = value.format_value(self.decimal_points);
-                    print!("{:width$}", value_str.unwrap(), width = column_widths[j]);
+                    out.push_str(&format!(
+                        "{:width$}",
+                        value_str.unwrap(),
+                        width = *column_width
+                    ));
                     if j < cols - 1 {
-                        print!(", ");
+                        out.push_str(", ");
                     }
                 }
-                println!("]");
+                out.push_str("]\n");
             }
-            println!("]");
+            out.push_str("]\n");
         } else if ndim == 3 {
             let depth = dims[0];
             let rows = dims[1];
             let cols = dims[2];
 
+            if depth == 0 {
+                return "[]\n".to_string();
+            }
+
             let mut column_widths = vec![0; cols];
             for i in 0..depth {
                 for j in 0..rows {
-                    for k in 0..cols {
+                    for (k, column_width) in column_widths.iter_mut().enumerate() {
                         let value = &self.array.data()[(i * rows * cols) + (j * cols) + k];
                         let width = value.format_value(self.decimal_points).unwrap().len();
-                        column_widths[k] = column_widths[k].max(width);
+                        *column_width = (*column_width).max(width);
                     }
                 }
             }
 
-            println!("[");
+            out.push_str("[\n");
             for i in 0..depth {
-                println!("   [");
+                if rows == 0 {
+                    out.push_str("   []\n");
+                    continue;
+                }
+
+                out.push_str("   [\n");
                 for j in 0..rows {
-                    print!("      [");
-                    for k in 0..cols {
+                    out.push_str("      [");
+                    for (k, column_width) in column_widths.iter().enumerate() {
                         let value = &self.array.data()[(i * rows * cols) + (j * cols) + k];
                         let value_str = value.format_value(self.decimal_points);
-                        print!("{:width$}", value_str.unwrap(), width = column_widths[k]);
+                        out.push_str(&format!(
+                            "{:width$}",
+                            value_str.unwrap(),
+                            width = *column_width
+                        ));
                         if k < cols - 1 {
-                            print!(", ");
+                            out.push_str(", ");
                         }
                     }
-                    println!("]");
+                    out.push_str("]\n");
                 }
-                println!("   ]");
+                out.push_str("   ]\n");
             }
-            println!("]");
+            out.push_str("]\n");
         } else {
             // Handle higher dimensions (4D, 5D, etc.) in the future if needed
-            println!("Unsupported dimension: {}", ndim);
+            out.push_str(&format!("Unsupported dimension: {}\n", ndim));
         }
+
+        out
     }
 }